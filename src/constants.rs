@@ -0,0 +1,78 @@
+//! This module provides physical constants as typed quantities, so they carry their dimension like any other value.
+//!
+//! Values are taken from the CODATA recommended values of the fundamental physical constants, at the revision given by `CODATA_REVISION`.
+//! Since the 2019 redefinition of the SI, the speed of light, the Planck constant, the elementary charge, the Boltzmann constant, and the
+//! Avogadro constant are exact, as are the conventional values of standard gravity and the standard atmosphere. The remaining constants
+//! are measured, and may change between revisions.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusty_units::constants::{SPEED_OF_LIGHT, STANDARD_GRAVITY};
+//! use rusty_units::units::{Distance, Mass, Time};
+//!
+//! let weight = Mass::from_kilograms(2.0) * STANDARD_GRAVITY;
+//! assert!((weight.as_newtons() - 19.6133).abs() < 1e-9);
+//!
+//! let light_second: Distance = SPEED_OF_LIGHT * Time::from_seconds(1.0);
+//! assert_eq!(light_second.as_meters(), 299_792_458.0);
+//! ```
+use crate::units::{AngularMomentum, Charge, Entropy, LinearAcceleration, LinearVelocity, Mass, Pressure, ReciprocalAmount};
+
+/// The CODATA revision the values in this module are taken from.
+pub const CODATA_REVISION: &str = "CODATA 2022";
+
+/// The speed of light in vacuum, c, exact.
+pub const SPEED_OF_LIGHT: LinearVelocity = LinearVelocity(299_792_458.0);
+
+/// The Planck constant, h, exact. It has the dimension of action, J·s, which is the same as angular momentum.
+pub const PLANCK_CONSTANT: AngularMomentum = AngularMomentum(6.626_070_15e-34);
+
+/// The reduced Planck constant, ħ = h / 2π.
+pub const REDUCED_PLANCK_CONSTANT: AngularMomentum = AngularMomentum(6.626_070_15e-34 / (2.0 * std::f64::consts::PI));
+
+/// The elementary charge, e, exact.
+pub const ELEMENTARY_CHARGE: Charge = Charge(1.602_176_634e-19);
+
+/// The Boltzmann constant, k, exact.
+pub const BOLTZMANN_CONSTANT: Entropy = Entropy(1.380_649e-23);
+
+/// The Avogadro constant, N_A, exact. It counts entities per mole, so multiplying it by an amount of substance gives a plain number.
+pub const AVOGADRO_CONSTANT: ReciprocalAmount = ReciprocalAmount(6.022_140_76e23);
+
+/// The standard acceleration of gravity, g_n, exact by convention.
+pub const STANDARD_GRAVITY: LinearAcceleration = LinearAcceleration(9.806_65);
+
+/// The standard atmosphere, exact by convention.
+pub const STANDARD_ATMOSPHERE: Pressure = Pressure(101_325.0);
+
+/// The electron mass, m_e.
+pub const ELECTRON_MASS: Mass = Mass(9.109_383_713_9e-31);
+
+/// The proton mass, m_p.
+pub const PROTON_MASS: Mass = Mass(1.672_621_925_95e-27);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_conventional_values_match_units() {
+        assert_eq!(STANDARD_ATMOSPHERE.as_atmospheres(), 1.0);
+        let weight = Mass::from_pounds(1.0) * STANDARD_GRAVITY;
+        assert!((weight.as_pounds_force() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_derived_values() {
+        let energy = ELEMENTARY_CHARGE * ElectricPotential::from_volts(1.0);
+        assert!((energy.as_joules() - 1.602_176_634e-19).abs() < 1e-30);
+        assert!((PLANCK_CONSTANT.0 / REDUCED_PLANCK_CONSTANT.0 - 2.0 * std::f64::consts::PI).abs() < EPSILON);
+        assert!((PROTON_MASS.0 / ELECTRON_MASS.0 - 1836.152673426).abs() < 1e-6);
+        let entities = AmountOfSubstance::from_millimoles(1.0) * AVOGADRO_CONSTANT;
+        assert!((entities / 6.022_140_76e20 - 1.0).abs() < EPSILON);
+    }
+}
@@ -28,4 +28,167 @@
 pub trait Dimension {
     fn as_base_units(&self) -> f64;
     fn from_base_units(value: f64) -> Self;
-}
\ No newline at end of file
+}
+
+/// The exponents of the seven SI base dimensions that make up a quantity, ie torque and energy are both `length: 2, mass: 1, time: -2`.
+///
+/// Quantities like angles, which are ratios of quantities with the same dimension, are dimensionless.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_units::dimension::SiDimension;
+///
+/// let length = SiDimension { length: 1, ..SiDimension::DIMENSIONLESS };
+/// let time = SiDimension { time: 1, ..SiDimension::DIMENSIONLESS };
+/// let velocity = length.div(time);
+///
+/// assert_eq!(velocity.mul(time), length);
+/// assert_eq!(velocity.to_string(), "m·s^-1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SiDimension {
+    pub length: i8,
+    pub mass: i8,
+    pub time: i8,
+    pub current: i8,
+    pub temperature: i8,
+    pub amount: i8,
+    pub luminous_intensity: i8,
+}
+
+impl SiDimension {
+    pub const DIMENSIONLESS: SiDimension = SiDimension {
+        length: 0,
+        mass: 0,
+        time: 0,
+        current: 0,
+        temperature: 0,
+        amount: 0,
+        luminous_intensity: 0,
+    };
+
+    /// The dimension of the product of two quantities.
+    pub const fn mul(self, other: SiDimension) -> SiDimension {
+        SiDimension {
+            length: self.length + other.length,
+            mass: self.mass + other.mass,
+            time: self.time + other.time,
+            current: self.current + other.current,
+            temperature: self.temperature + other.temperature,
+            amount: self.amount + other.amount,
+            luminous_intensity: self.luminous_intensity + other.luminous_intensity,
+        }
+    }
+
+    /// The dimension of the quotient of two quantities.
+    pub const fn div(self, other: SiDimension) -> SiDimension {
+        SiDimension {
+            length: self.length - other.length,
+            mass: self.mass - other.mass,
+            time: self.time - other.time,
+            current: self.current - other.current,
+            temperature: self.temperature - other.temperature,
+            amount: self.amount - other.amount,
+            luminous_intensity: self.luminous_intensity - other.luminous_intensity,
+        }
+    }
+
+    /// Compares two dimensions, usable in constant expressions unlike `==`.
+    pub const fn equals(self, other: SiDimension) -> bool {
+        self.length == other.length
+            && self.mass == other.mass
+            && self.time == other.time
+            && self.current == other.current
+            && self.temperature == other.temperature
+            && self.amount == other.amount
+            && self.luminous_intensity == other.luminous_intensity
+    }
+}
+
+impl std::fmt::Display for SiDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bases = [
+            ("m", self.length),
+            ("kg", self.mass),
+            ("s", self.time),
+            ("A", self.current),
+            ("K", self.temperature),
+            ("mol", self.amount),
+            ("cd", self.luminous_intensity),
+        ];
+        let mut written = false;
+        for (symbol, exponent) in bases.into_iter().filter(|(_, exponent)| *exponent != 0) {
+            if written {
+                write!(f, "·")?;
+            }
+            match exponent {
+                1 => write!(f, "{symbol}")?,
+                _ => write!(f, "{symbol}^{exponent}")?,
+            }
+            written = true;
+        }
+        if !written {
+            write!(f, "1")?;
+        }
+        Ok(())
+    }
+}
+
+/// A trait tagging a dimension struct as a distinct kind of quantity with a known SI dimension.
+///
+/// Different kinds can share an SI dimension, like `Torque` and `Energy`, or `Frequency` and `AngularVelocity`, without being interchangeable.
+/// The kind is what keeps them apart, even in the dynamically dimensioned `Quantity` type.
+///
+/// This trait is implemented with the `create_kind` macro.
+pub trait Kind: Dimension + 'static {
+    /// The name of the kind, which is the name of the struct.
+    const NAME: &'static str;
+    /// The SI dimension of the kind.
+    const SI_DIMENSION: SiDimension;
+
+    /// The operations this kind declares with `create_relationships`, which `Quantity` uses alongside the operations between the built-in kinds.
+    fn operations() -> &'static [crate::quantity::KindOperation] {
+        &[]
+    }
+}
+
+/// A trait for dimensions whose `N`th power is the dimension `Output`, like `Distance`, whose square is an `Area`.
+///
+/// This trait is implemented by the `^ 2` and `^ 3` relationships of the `create_unit_operations` macro, and called with the power to raise to,
+/// ie `Powi::<2>::powi(&distance)`, or generically with a `Powi<N>` bound. The same relationships generate `squared` and `cubed` methods.
+///
+/// ```rust
+/// use rusty_units::dimension::Powi;
+/// use rusty_units::units::Distance;
+///
+/// let area = Powi::<2>::powi(&Distance::from_meters(3.0));
+/// assert_eq!(area.as_square_meters(), 9.0);
+/// ```
+pub trait Powi<const N: i32> {
+    type Output;
+
+    fn powi(&self) -> Self::Output;
+}
+
+/// A trait for the hypotenuse of two values of the same dimension, implemented for every `Dimension`.
+///
+/// It's a trait, rather than a method of each unit struct, so that it doesn't conflict with a `hypot` method of a struct created with `create_unit!`.
+///
+/// ```rust
+/// use rusty_units::dimension::Hypot;
+/// use rusty_units::units::Distance;
+///
+/// let diagonal = Distance::from_meters(3.0).hypot(Distance::from_meters(4.0));
+/// assert_eq!(diagonal.as_meters(), 5.0);
+/// ```
+pub trait Hypot {
+    /// Returns the hypotenuse of a right triangle with legs `self` and `other`, ie the magnitude of a vector with those components.
+    fn hypot(&self, other: Self) -> Self;
+}
+
+impl<D: Dimension> Hypot for D {
+    fn hypot(&self, other: Self) -> Self {
+        D::from_base_units(self.as_base_units().hypot(other.as_base_units()))
+    }
+}
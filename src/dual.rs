@@ -0,0 +1,216 @@
+//! This module provides `Dual`, a forward mode automatic differentiation number over unit types.
+//!
+//! A `Dual<D, P>` holds a value of dimension `D` along with its derivative with respect to a parameter of dimension `P`.
+//! Derivatives propagate through addition, subtraction, scaling, and any multiplication or division defined between dimensions
+//! with `create_unit_operations!`. The derivative is stored in base units, and can be read back as a typed value whenever
+//! `D / P` is itself a defined operation, so the derivative of a `Distance` with respect to a `Time` is a `LinearVelocity`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusty_units::dual::Dual;
+//! use rusty_units::units::{Distance, LinearAcceleration, LinearVelocity, Time};
+//!
+//! // The average velocity over a fixed 100 m distance, as the time taken to cover it changes.
+//! let time = Dual::variable(Time::from_seconds(10.0));
+//! let distance = Dual::constant(Distance::from_meters(100.0));
+//! let velocity = distance / time;
+//!
+//! let rate: LinearAcceleration = velocity.derivative();
+//! assert_eq!(velocity.value().as_meters_per_second(), 10.0);
+//! assert_eq!(rate.as_meters_per_second_squared(), -1.0);
+//!
+//! // A distance whose derivative with respect to time is read back as a velocity.
+//! let position = Dual::<Distance, Time>::new(Distance::from_meters(10.0), LinearVelocity::from_meters_per_second(2.0));
+//! let velocity: LinearVelocity = (position * 3.0).derivative();
+//! assert_eq!(velocity.as_meters_per_second(), 6.0);
+//! ```
+use std::marker::PhantomData;
+
+use crate::dimension::Dimension;
+
+/// A value of dimension `D` and its derivative with respect to a parameter of dimension `P`.
+#[derive(Debug)]
+pub struct Dual<D, P> {
+    value: D,
+    derivative: f64,
+    parameter: PhantomData<fn() -> P>,
+}
+
+impl<D: Clone, P> Clone for Dual<D, P> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            derivative: self.derivative,
+            parameter: PhantomData,
+        }
+    }
+}
+
+impl<D: Copy, P> Copy for Dual<D, P> {}
+
+impl<D: Dimension> Dual<D, D> {
+    /// Creates the parameter being differentiated with respect to, its derivative with respect to itself is 1.
+    pub fn variable(value: D) -> Self {
+        Self::from_base_units(value.as_base_units(), 1.0)
+    }
+}
+
+impl<D: Dimension, P> Dual<D, P> {
+    /// Creates a value that doesn't depend on the parameter.
+    pub fn constant(value: D) -> Self {
+        Self::from_base_units(value.as_base_units(), 0.0)
+    }
+
+    /// Creates a value with a known derivative, which must have the dimension `D / P`.
+    pub fn new<Q>(value: D, derivative: Q) -> Self
+    where
+        D: std::ops::Div<P, Output = Q>,
+        Q: Dimension,
+    {
+        Self::from_base_units(value.as_base_units(), derivative.as_base_units())
+    }
+
+    fn from_base_units(value: f64, derivative: f64) -> Self {
+        Self {
+            value: D::from_base_units(value),
+            derivative,
+            parameter: PhantomData,
+        }
+    }
+
+    pub fn value(&self) -> &D {
+        &self.value
+    }
+
+    /// The derivative with respect to the parameter, as a value of dimension `D / P`.
+    pub fn derivative<Q>(&self) -> Q
+    where
+        D: std::ops::Div<P, Output = Q>,
+        Q: Dimension,
+    {
+        Q::from_base_units(self.derivative)
+    }
+
+    /// The derivative with respect to the parameter in base units, for when `D / P` isn't a defined operation.
+    pub fn derivative_base_units(&self) -> f64 {
+        self.derivative
+    }
+}
+
+impl<D: Dimension, P> std::ops::Add for Dual<D, P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_base_units(
+            self.value.as_base_units() + other.value.as_base_units(),
+            self.derivative + other.derivative,
+        )
+    }
+}
+
+impl<D: Dimension, P> std::ops::Sub for Dual<D, P> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_base_units(
+            self.value.as_base_units() - other.value.as_base_units(),
+            self.derivative - other.derivative,
+        )
+    }
+}
+
+impl<D: Dimension, P> std::ops::Mul<f64> for Dual<D, P> {
+    type Output = Self;
+
+    fn mul(self, scalar: f64) -> Self {
+        Self::from_base_units(self.value.as_base_units() * scalar, self.derivative * scalar)
+    }
+}
+
+impl<D: Dimension, P> std::ops::Div<f64> for Dual<D, P> {
+    type Output = Self;
+
+    fn div(self, scalar: f64) -> Self {
+        Self::from_base_units(self.value.as_base_units() / scalar, self.derivative / scalar)
+    }
+}
+
+impl<L, R, O, P> std::ops::Mul<Dual<R, P>> for Dual<L, P>
+where
+    L: Dimension + std::ops::Mul<R, Output = O>,
+    R: Dimension,
+    O: Dimension,
+{
+    type Output = Dual<O, P>;
+
+    fn mul(self, rhs: Dual<R, P>) -> Dual<O, P> {
+        let (l, r) = (self.value.as_base_units(), rhs.value.as_base_units());
+        Dual::from_base_units(l * r, self.derivative * r + l * rhs.derivative)
+    }
+}
+
+impl<L, R, O, P> std::ops::Div<Dual<R, P>> for Dual<L, P>
+where
+    L: Dimension + std::ops::Div<R, Output = O>,
+    R: Dimension,
+    O: Dimension,
+{
+    type Output = Dual<O, P>;
+
+    fn div(self, rhs: Dual<R, P>) -> Dual<O, P> {
+        let (l, r) = (self.value.as_base_units(), rhs.value.as_base_units());
+        Dual::from_base_units(l / r, (self.derivative * r - l * rhs.derivative) / (r * r))
+    }
+}
+
+impl<D: Dimension, P> PartialEq for Dual<D, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.as_base_units() == other.value.as_base_units() && self.derivative == other.derivative
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_variable_and_constant() {
+        let t = Dual::<Time, Time>::variable(Time::from_seconds(3.0));
+        assert_eq!(t.derivative_base_units(), 1.0);
+        let d = Dual::<Distance, Time>::constant(Distance::from_meters(3.0));
+        assert_eq!(d.derivative::<LinearVelocity>().as_meters_per_second(), 0.0);
+    }
+
+    #[test]
+    fn test_typed_derivative() {
+        let position = Dual::<Distance, Time>::new(Distance::from_feet(10.0), LinearVelocity::from_feet_per_second(2.0));
+        let doubled = position + position * 0.5;
+        let velocity: LinearVelocity = doubled.derivative();
+        assert!((velocity.as_feet_per_second() - 3.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_quotient_rule() {
+        // v = d / t with a fixed distance, so dv/dt = -d / t^2.
+        let t = Dual::variable(Time::from_seconds(2.0));
+        let d = Dual::constant(Distance::from_meters(8.0));
+        let velocity = d / t;
+        let acceleration: LinearAcceleration = velocity.derivative();
+        assert!((velocity.value().as_meters_per_second() - 4.0).abs() < EPSILON);
+        assert!((acceleration.as_meters_per_second_squared() + 2.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_product_rule() {
+        // Torque from a force of 5 + 2x N applied at a lever arm x, so dT/dx = 2x + F.
+        let x = Dual::variable(Distance::from_meters(3.0));
+        let force = Dual::<Force, Distance>::from_base_units(5.0 + 2.0 * 3.0, 2.0);
+        let torque = force * x;
+        assert!((torque.value().as_newton_meters() - 33.0).abs() < EPSILON);
+        assert!((torque.derivative_base_units() - 17.0).abs() < EPSILON);
+    }
+}
@@ -0,0 +1,318 @@
+//! This module provides `Interval`, a closed range of values of any dimension, for reasoning about bounds and safety envelopes.
+//!
+//! Interval arithmetic is sound: the result of an operation contains every result of the operation applied to values from its operands.
+//! Results are rounded outwards by one ulp so that floating-point rounding can't make a bound too tight. Any multiplication or division defined
+//! between dimensions with `create_unit_operations!` is also defined between their intervals. Multiplying by NaN, or dividing by zero, by NaN,
+//! or by an interval containing zero is unbounded, and results in the whole real line. Bounds can be infinite, but never NaN, and zero times
+//! an infinite bound is zero.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusty_units::interval::Interval;
+//! use rusty_units::units::{Distance, LinearVelocity, Time};
+//!
+//! let distance = Interval::from_units(Distance::from_feet, 90.0, 110.0);
+//! let time = Interval::new(Time::from_seconds(9.0), Time::from_seconds(10.0));
+//!
+//! let velocity = distance / time;
+//! assert!(velocity.contains(&LinearVelocity::from_feet_per_second(10.0)));
+//! assert!(!velocity.contains(&LinearVelocity::from_feet_per_second(12.5)));
+//!
+//! let (min, max) = velocity.as_units(LinearVelocity::as_feet_per_second);
+//! assert!(min <= 9.0 && max >= 110.0 / 9.0);
+//! ```
+use crate::dimension::Dimension;
+
+/// A closed interval of values of dimension `D`, from `min` to `max` inclusive.
+#[derive(Debug, Clone, Copy)]
+pub struct Interval<D> {
+    min: D,
+    max: D,
+}
+
+impl<D: Dimension> Interval<D> {
+    /// Creates the interval between two values, in either order.
+    ///
+    /// # Panics
+    /// Panics if either value is NaN.
+    pub fn new(a: D, b: D) -> Self {
+        let (a, b) = (a.as_base_units(), b.as_base_units());
+        assert!(!a.is_nan() && !b.is_nan(), "interval bounds can't be NaN");
+        Self::from_base_units(a, b)
+    }
+
+    /// Creates the interval containing only `value`.
+    ///
+    /// # Panics
+    /// Panics if `value` is NaN.
+    pub fn point(value: D) -> Self {
+        let value = value.as_base_units();
+        assert!(!value.is_nan(), "interval bounds can't be NaN");
+        Self::from_base_units(value, value)
+    }
+
+    /// Creates an interval from bounds expressed in the unit given by `unit`, ie `Distance::from_feet`.
+    ///
+    /// # Panics
+    /// Panics if either bound is NaN.
+    pub fn from_units(unit: fn(f64) -> D, a: f64, b: f64) -> Self {
+        Self::new(unit(a), unit(b))
+    }
+
+    fn from_base_units(a: f64, b: f64) -> Self {
+        Self {
+            min: D::from_base_units(a.min(b)),
+            max: D::from_base_units(a.max(b)),
+        }
+    }
+
+    /// The whole real line, the result of an unbounded operation.
+    fn unbounded() -> Self {
+        Self::from_base_units(f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// Creates an interval from bounds in base units, rounding each bound outwards.
+    fn rounded_outwards(min: f64, max: f64) -> Self {
+        Self::from_base_units(min.next_down(), max.next_up())
+    }
+
+    pub fn min(&self) -> &D {
+        &self.min
+    }
+
+    pub fn max(&self) -> &D {
+        &self.max
+    }
+
+    /// Returns the bounds expressed in the unit given by `unit`, ie `Distance::as_feet`.
+    pub fn as_units(&self, unit: fn(&D) -> f64) -> (f64, f64) {
+        (unit(&self.min), unit(&self.max))
+    }
+
+    pub fn width(&self) -> D {
+        D::from_base_units(self.max.as_base_units() - self.min.as_base_units())
+    }
+
+    pub fn midpoint(&self) -> D {
+        D::from_base_units((self.min.as_base_units() + self.max.as_base_units()) / 2.0)
+    }
+
+    pub fn contains(&self, value: &D) -> bool {
+        let value = value.as_base_units();
+        self.min.as_base_units() <= value && value <= self.max.as_base_units()
+    }
+
+    /// Returns whether every value in `other` is also in this interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.min.as_base_units() <= other.min.as_base_units() && other.max.as_base_units() <= self.max.as_base_units()
+    }
+
+    /// Returns the values in both intervals, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = self.min.as_base_units().max(other.min.as_base_units());
+        let max = self.max.as_base_units().min(other.max.as_base_units());
+        (min <= max).then(|| Self::from_base_units(min, max))
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    pub fn hull(&self, other: &Self) -> Self {
+        Self::from_base_units(
+            self.min.as_base_units().min(other.min.as_base_units()),
+            self.max.as_base_units().max(other.max.as_base_units()),
+        )
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        (self.min.as_base_units(), self.max.as_base_units())
+    }
+}
+
+/// Returns the smallest and largest products of every pair of bounds.
+///
+/// Bounds are never NaN, so a NaN product is zero times an infinite bound. Every value in an interval is finite, and zero times any of them
+/// is zero, so the product is taken as zero, which keeps the result ordered.
+fn products(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let product = |x: f64, y: f64| if x == 0.0 || y == 0.0 { 0.0 } else { x * y };
+    let candidates = [product(a.0, b.0), product(a.0, b.1), product(a.1, b.0), product(a.1, b.1)];
+    let min = candidates.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = candidates.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    (min, max)
+}
+
+impl<D: Dimension> std::ops::Add for Interval<D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let ((a, b), (c, d)) = (self.bounds(), other.bounds());
+        Self::rounded_outwards(a + c, b + d)
+    }
+}
+
+impl<D: Dimension> std::ops::Sub for Interval<D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let ((a, b), (c, d)) = (self.bounds(), other.bounds());
+        Self::rounded_outwards(a - d, b - c)
+    }
+}
+
+impl<D: Dimension> std::ops::Mul<f64> for Interval<D> {
+    type Output = Self;
+
+    /// Multiplying by NaN is unbounded, and results in the whole real line.
+    fn mul(self, scalar: f64) -> Self {
+        if scalar.is_nan() {
+            return Self::unbounded();
+        }
+        let (min, max) = products(self.bounds(), (scalar, scalar));
+        Self::rounded_outwards(min, max)
+    }
+}
+
+impl<D: Dimension> std::ops::Div<f64> for Interval<D> {
+    type Output = Self;
+
+    /// Dividing by zero or NaN is unbounded, and results in the whole real line.
+    fn div(self, scalar: f64) -> Self {
+        if scalar == 0.0 || scalar.is_nan() {
+            return Self::unbounded();
+        }
+        let (a, b) = self.bounds();
+        Self::rounded_outwards((a / scalar).min(b / scalar), (a / scalar).max(b / scalar))
+    }
+}
+
+impl<L, R, O> std::ops::Mul<Interval<R>> for Interval<L>
+where
+    L: Dimension + std::ops::Mul<R, Output = O>,
+    R: Dimension,
+    O: Dimension,
+{
+    type Output = Interval<O>;
+
+    fn mul(self, rhs: Interval<R>) -> Interval<O> {
+        let (min, max) = products(self.bounds(), rhs.bounds());
+        Interval::rounded_outwards(min, max)
+    }
+}
+
+impl<L, R, O> std::ops::Div<Interval<R>> for Interval<L>
+where
+    L: Dimension + std::ops::Div<R, Output = O>,
+    R: Dimension,
+    O: Dimension,
+{
+    type Output = Interval<O>;
+
+    /// Dividing by an interval containing zero is unbounded, and results in the whole real line.
+    fn div(self, rhs: Interval<R>) -> Interval<O> {
+        let (c, d) = rhs.bounds();
+        if c <= 0.0 && d >= 0.0 {
+            return Interval::unbounded();
+        }
+        // The reciprocals are rounded too, so the result is widened by an extra ulp to account for them.
+        let (min, max) = products(self.bounds(), (1.0 / d, 1.0 / c));
+        Interval::rounded_outwards(min.next_down(), max.next_up())
+    }
+}
+
+impl<D: Dimension> PartialEq for Interval<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bounds() == other.bounds()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::*;
+
+    fn meters(a: f64, b: f64) -> Interval<Distance> {
+        Interval::from_units(Distance::from_meters, a, b)
+    }
+
+    #[test]
+    fn test_bounds_are_ordered() {
+        let interval = meters(5.0, -1.0);
+        assert_eq!(interval.as_units(Distance::as_meters), (-1.0, 5.0));
+        assert_eq!(interval.width().as_meters(), 6.0);
+        assert_eq!(interval.midpoint().as_meters(), 2.0);
+    }
+
+    #[test]
+    fn test_containment_intersection_and_hull() {
+        let a = meters(0.0, 10.0);
+        let b = meters(5.0, 15.0);
+        assert!(a.contains(&Distance::from_meters(10.0)));
+        assert!(!a.contains(&Distance::from_meters(10.5)));
+        assert!(a.contains_interval(&meters(2.0, 3.0)));
+        assert!(!a.contains_interval(&b));
+        assert_eq!(a.intersection(&b), Some(meters(5.0, 10.0)));
+        assert_eq!(a.intersection(&meters(11.0, 12.0)), None);
+        assert_eq!(a.hull(&b), meters(0.0, 15.0));
+    }
+
+    #[test]
+    fn test_arithmetic_is_sound() {
+        let sum = meters(1.0, 2.0) + meters(0.1, 0.2);
+        assert!(sum.contains_interval(&meters(1.1, 2.2)));
+        let difference = meters(1.0, 2.0) - meters(0.1, 0.2);
+        assert!(difference.contains_interval(&meters(0.8, 1.9)));
+        assert!(!difference.contains(&Distance::from_meters(0.79)));
+        let scaled = meters(1.0, 2.0) * -3.0;
+        assert!(scaled.contains_interval(&meters(-6.0, -3.0)));
+    }
+
+    #[test]
+    fn test_cross_dimension_operations() {
+        let force = Interval::from_units(Force::from_newtons, -2.0, 3.0);
+        let torque = force * meters(1.0, 2.0);
+        assert!(torque.contains_interval(&Interval::from_units(Torque::from_newton_meters, -4.0, 6.0)));
+        assert!(!torque.contains(&Torque::from_newton_meters(6.1)));
+
+        let time = Interval::from_units(Time::from_seconds, 2.0, 4.0);
+        let velocity = meters(8.0, 12.0) / time;
+        assert!(velocity.contains_interval(&Interval::from_units(LinearVelocity::from_meters_per_second, 2.0, 6.0)));
+        assert!(!velocity.contains(&LinearVelocity::from_meters_per_second(6.1)));
+    }
+
+    #[test]
+    fn test_division_by_interval_containing_zero() {
+        let time = Interval::from_units(Time::from_seconds, -1.0, 1.0);
+        let velocity = meters(1.0, 2.0) / time;
+        assert_eq!(velocity.as_units(LinearVelocity::as_meters_per_second), (f64::NEG_INFINITY, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_division_by_zero_or_nan_scalar() {
+        let unbounded = (f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!((meters(1.0, 2.0) / 0.0).as_units(Distance::as_meters), unbounded);
+        assert_eq!((meters(0.0, 0.0) / -0.0).as_units(Distance::as_meters), unbounded);
+        assert_eq!((meters(1.0, 2.0) / f64::NAN).as_units(Distance::as_meters), unbounded);
+        assert!((meters(1.0, 2.0) / 4.0).contains_interval(&meters(0.25, 0.5)));
+    }
+
+    #[test]
+    fn test_multiplication_by_nan_and_infinite_bounds() {
+        let unbounded = (f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!((meters(1.0, 2.0) * f64::NAN).as_units(Distance::as_meters), unbounded);
+
+        let zero = Interval::from_units(Force::from_newtons, 0.0, 0.0);
+        let torque = zero * meters(f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!(torque.as_units(Torque::as_newton_meters), (0.0_f64.next_down(), 0.0_f64.next_up()));
+        let torque = Interval::from_units(Force::from_newtons, 0.0, 1.0) * meters(1.0, f64::INFINITY);
+        let (min, max) = torque.as_units(Torque::as_newton_meters);
+        assert!(min <= 0.0 && max == f64::INFINITY);
+        assert_eq!((meters(0.0, 0.0) * f64::INFINITY).as_units(Distance::as_meters), (0.0_f64.next_down(), 0.0_f64.next_up()));
+    }
+
+    #[test]
+    fn test_nan_bounds_are_rejected() {
+        assert!(std::panic::catch_unwind(|| meters(f64::NAN, 1.0)).is_err());
+        assert!(std::panic::catch_unwind(|| meters(1.0, f64::NAN)).is_err());
+        assert!(std::panic::catch_unwind(|| Interval::point(Distance::from_meters(f64::NAN))).is_err());
+        assert_eq!(meters(f64::NEG_INFINITY, 1.0).as_units(Distance::as_meters), (f64::NEG_INFINITY, 1.0));
+    }
+}
@@ -5,6 +5,26 @@
 //! - `dimension`: Contains definitions and operations related to dimensions.
 //! - `utilities`: Provides utility functions and helpers for unit conversions and calculations.
 //! - `units`: Defines various units and their properties.
+//! - `quantity`: Dynamically dimensioned quantities which keep kinds sharing an SI dimension apart.
+//! - `measured`: Quantities with a standard uncertainty, propagated through unit operations.
+//! - `interval`: Closed intervals of quantities with sound interval arithmetic.
+//! - `dual`: Dual numbers for automatic differentiation through unit operations.
+//! - `logarithmic`: Logarithmic gains and levels, like decibels, dBm, and dB SPL.
+//! - `constants`: Physical constants from CODATA as typed quantities.
+//! - `time`: Conversions between `Time` and the standard library time types, and absolute timestamps.
+//! - `rational`: Exact rational storage and conversions for units, enabled with the `rational` feature.
 pub mod dimension;
 pub mod utilities;
-pub mod units;
\ No newline at end of file
+pub mod units;
+pub mod quantity;
+pub mod measured;
+pub mod interval;
+pub mod dual;
+pub mod logarithmic;
+pub mod constants;
+pub mod time;
+#[cfg(feature = "rational")]
+pub mod rational;
+
+#[doc(hidden)]
+pub use paste;
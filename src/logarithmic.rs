@@ -0,0 +1,293 @@
+//! This module provides logarithmic levels, like decibels and nepers, which can't be expressed with the linear conversion factors used by `create_unit!`.
+//!
+//! A `Gain` is a dimensionless ratio expressed logarithmically, and a `Level` is a quantity expressed as a gain relative to a reference value of the
+//! same dimension, ie dBm is a `Level<Power>` relative to 1 mW. Level arithmetic is kept distinct from linear arithmetic: adding a gain to a level
+//! multiplies the underlying quantity, and subtracting two levels gives the gain between them. To add the quantities themselves, convert the levels
+//! back with `Level::value` and use the usual operators.
+//!
+//! Power quantities, like `Power`, have a level of 10 log10 of their ratio to the reference, and root-power quantities, like `ElectricPotential`
+//! and `Pressure`, have a level of 20 log10, so that a gain in decibels means the same thing for both.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusty_units::logarithmic::{Gain, Level};
+//! use rusty_units::units::{ElectricPotential, Power};
+//!
+//! assert_eq!(Power::from_watts(1e-3).to_dbm(), 0.0);
+//!
+//! let transmitted = Level::new(Power::from_watts(0.1), Power::from_watts(1e-3));
+//! let received = transmitted + Gain::from_decibels(-30.0);
+//! assert!((received.decibels() + 10.0).abs() < 1e-9);
+//! assert!((received.value().as_watts() - 1e-4).abs() < 1e-12);
+//!
+//! assert!((ElectricPotential::from_volts(10.0).to_dbv() - 20.0).abs() < 1e-9);
+//! ```
+use std::f64::consts::LN_10;
+
+use crate::dimension::Dimension;
+use crate::units::{Current, ElectricPotential, Irradiance, Power, Pressure};
+
+/// A quantity that can be expressed as a logarithmic level.
+pub trait LogarithmicQuantity: Dimension {
+    /// The decibels per decade of ratio, 10 for power quantities and 20 for root-power quantities.
+    const DECIBELS_PER_DECADE: f64;
+}
+
+impl LogarithmicQuantity for Power {
+    const DECIBELS_PER_DECADE: f64 = 10.0;
+}
+
+impl LogarithmicQuantity for Irradiance {
+    const DECIBELS_PER_DECADE: f64 = 10.0;
+}
+
+impl LogarithmicQuantity for ElectricPotential {
+    const DECIBELS_PER_DECADE: f64 = 20.0;
+}
+
+impl LogarithmicQuantity for Current {
+    const DECIBELS_PER_DECADE: f64 = 20.0;
+}
+
+impl LogarithmicQuantity for Pressure {
+    const DECIBELS_PER_DECADE: f64 = 20.0;
+}
+
+/// Decibels in one neper, for a root-power ratio.
+const DECIBELS_PER_NEPER: f64 = 20.0 / LN_10;
+
+/// A dimensionless ratio expressed logarithmically, stored in decibels.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Gain(pub f64);
+
+impl Gain {
+    pub fn as_decibels(&self) -> f64 {
+        self.0
+    }
+
+    pub fn from_decibels(value: f64) -> Self {
+        Gain(value)
+    }
+
+    pub fn as_nepers(&self) -> f64 {
+        self.0 / DECIBELS_PER_NEPER
+    }
+
+    pub fn from_nepers(value: f64) -> Self {
+        Gain(value * DECIBELS_PER_NEPER)
+    }
+
+    /// The ratio between two power quantities with this gain, so 10 dB is a ratio of 10.
+    pub fn as_power_ratio(&self) -> f64 {
+        10f64.powf(self.0 / 10.0)
+    }
+
+    pub fn from_power_ratio(ratio: f64) -> Self {
+        Gain(10.0 * ratio.log10())
+    }
+
+    /// The ratio between two root-power quantities with this gain, so 20 dB is a ratio of 10.
+    pub fn as_amplitude_ratio(&self) -> f64 {
+        10f64.powf(self.0 / 20.0)
+    }
+
+    pub fn from_amplitude_ratio(ratio: f64) -> Self {
+        Gain(20.0 * ratio.log10())
+    }
+}
+
+impl std::ops::Add for Gain {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Gain(self.0 + other.0)
+    }
+}
+
+impl std::ops::Sub for Gain {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Gain(self.0 - other.0)
+    }
+}
+
+impl std::ops::Neg for Gain {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Gain(-self.0)
+    }
+}
+
+/// A quantity of dimension `D` expressed as its gain relative to a reference value.
+#[derive(Debug, Clone, Copy)]
+pub struct Level<D> {
+    gain: Gain,
+    reference: D,
+}
+
+impl<D: LogarithmicQuantity + Copy> Level<D> {
+    /// Creates the level of `value` relative to `reference`.
+    pub fn new(value: D, reference: D) -> Self {
+        let ratio = value.as_base_units() / reference.as_base_units();
+        Self::from_decibels(D::DECIBELS_PER_DECADE * ratio.log10(), reference)
+    }
+
+    pub fn from_decibels(decibels: f64, reference: D) -> Self {
+        Self {
+            gain: Gain::from_decibels(decibels),
+            reference,
+        }
+    }
+
+    pub fn decibels(&self) -> f64 {
+        self.gain.as_decibels()
+    }
+
+    pub fn nepers(&self) -> f64 {
+        self.gain.as_nepers()
+    }
+
+    pub fn reference(&self) -> D {
+        self.reference
+    }
+
+    /// Converts the level back into the linear quantity it represents.
+    pub fn value(&self) -> D {
+        let ratio = 10f64.powf(self.decibels() / D::DECIBELS_PER_DECADE);
+        D::from_base_units(self.reference.as_base_units() * ratio)
+    }
+
+    /// Expresses the same quantity relative to a different reference.
+    pub fn with_reference(&self, reference: D) -> Self {
+        Self::new(self.value(), reference)
+    }
+}
+
+impl<D: LogarithmicQuantity + Copy> std::ops::Add<Gain> for Level<D> {
+    type Output = Self;
+
+    fn add(self, gain: Gain) -> Self {
+        Self::from_decibels(self.decibels() + gain.as_decibels(), self.reference)
+    }
+}
+
+impl<D: LogarithmicQuantity + Copy> std::ops::Sub<Gain> for Level<D> {
+    type Output = Self;
+
+    fn sub(self, gain: Gain) -> Self {
+        Self::from_decibels(self.decibels() - gain.as_decibels(), self.reference)
+    }
+}
+
+impl<D: LogarithmicQuantity + Copy> std::ops::Sub for Level<D> {
+    type Output = Gain;
+
+    /// The gain from `other` to this level, even if they have different references.
+    fn sub(self, other: Self) -> Gain {
+        Gain::from_decibels(self.decibels() - other.with_reference(self.reference).decibels())
+    }
+}
+
+impl<D: LogarithmicQuantity + Copy> PartialEq for Level<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value().as_base_units() == other.value().as_base_units()
+    }
+}
+
+impl Power {
+    /// The level in decibels relative to 1 milliwatt.
+    pub fn to_dbm(&self) -> f64 {
+        Level::new(*self, Power::from_watts(1e-3)).decibels()
+    }
+
+    pub fn from_dbm(decibels: f64) -> Self {
+        Level::from_decibels(decibels, Power::from_watts(1e-3)).value()
+    }
+
+    /// The level in decibels relative to 1 watt.
+    pub fn to_dbw(&self) -> f64 {
+        Level::new(*self, Power::from_watts(1.0)).decibels()
+    }
+
+    pub fn from_dbw(decibels: f64) -> Self {
+        Level::from_decibels(decibels, Power::from_watts(1.0)).value()
+    }
+}
+
+impl ElectricPotential {
+    /// The level in decibels relative to 1 volt.
+    pub fn to_dbv(&self) -> f64 {
+        Level::new(*self, ElectricPotential::from_volts(1.0)).decibels()
+    }
+
+    pub fn from_dbv(decibels: f64) -> Self {
+        Level::from_decibels(decibels, ElectricPotential::from_volts(1.0)).value()
+    }
+}
+
+impl Pressure {
+    /// The sound pressure level in decibels relative to 20 micropascals.
+    pub fn to_db_spl(&self) -> f64 {
+        Level::new(*self, Pressure::from_pascals(20e-6)).decibels()
+    }
+
+    pub fn from_db_spl(decibels: f64) -> Self {
+        Level::from_decibels(decibels, Pressure::from_pascals(20e-6)).value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_gain_conversion() {
+        assert!((Gain::from_decibels(10.0).as_power_ratio() - 10.0).abs() < EPSILON);
+        assert!((Gain::from_decibels(20.0).as_amplitude_ratio() - 10.0).abs() < EPSILON);
+        assert!((Gain::from_power_ratio(2.0).as_decibels() - 3.010299956639812).abs() < EPSILON);
+        assert!((Gain::from_amplitude_ratio(0.5).as_decibels() + 6.020599913279624).abs() < EPSILON);
+        assert!((Gain::from_nepers(1.0).as_decibels() - 8.685889638065036).abs() < EPSILON);
+        assert!((Gain::from_amplitude_ratio(std::f64::consts::E).as_nepers() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_power_levels() {
+        assert_eq!(Power::from_watts(1e-3).to_dbm(), 0.0);
+        assert!((Power::from_watts(1.0).to_dbm() - 30.0).abs() < EPSILON);
+        assert!((Power::from_watts(100.0).to_dbw() - 20.0).abs() < EPSILON);
+        assert!((Power::from_dbm(-30.0).as_watts() - 1e-6).abs() < EPSILON);
+        assert!((Power::from_dbw(3.0).as_watts() - 1.9952623149688795).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_root_power_levels() {
+        assert!((ElectricPotential::from_volts(0.1).to_dbv() + 20.0).abs() < EPSILON);
+        assert!((ElectricPotential::from_dbv(6.0).as_volts() - 1.9952623149688795).abs() < EPSILON);
+        assert!((Pressure::from_pascals(1.0).to_db_spl() - 93.97940008672037).abs() < EPSILON);
+        assert!((Pressure::from_db_spl(120.0).as_pascals() - 20.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_level_arithmetic() {
+        let level = Level::new(Power::from_watts(1.0), Power::from_watts(1e-3));
+        let amplified = level + Gain::from_decibels(10.0) + Gain::from_decibels(10.0);
+        assert!((amplified.decibels() - 50.0).abs() < EPSILON);
+        assert!((amplified.value().as_watts() - 100.0).abs() < EPSILON);
+        let attenuated = level - Gain::from_decibels(30.0);
+        assert!((attenuated.value().as_watts() - 1e-3).abs() < EPSILON);
+        assert!(((amplified - attenuated).as_decibels() - 50.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_levels_with_different_references() {
+        let dbm = Level::new(Power::from_watts(2.0), Power::from_watts(1e-3));
+        let dbw = Level::new(Power::from_watts(1.0), Power::from_watts(1.0));
+        assert!(((dbm - dbw).as_decibels() - 3.010299956639812).abs() < EPSILON);
+        assert!((dbm.with_reference(Power::from_watts(1.0)).decibels() - 3.010299956639812).abs() < EPSILON);
+    }
+}
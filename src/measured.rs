@@ -0,0 +1,301 @@
+//! This module provides `Measured`, a quantity of any dimension paired with its standard uncertainty.
+//!
+//! Uncertainty is propagated to first order. Adding and subtracting measurements combines their uncertainties in quadrature,
+//! scaling by a number scales the uncertainty, and any multiplication or division defined between dimensions with
+//! `create_unit_operations!` is also defined between their measurements, combining relative uncertainties in quadrature.
+//!
+//! The operators assume the errors are independent, the `*_correlated` methods take a correlation coefficient for when they are not.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusty_units::measured::Measured;
+//! use rusty_units::units::{Distance, LinearVelocity, Time};
+//!
+//! let distance = Measured::new(Distance::from_meters(100.0), Distance::from_meters(2.0));
+//! let time = Measured::new(Time::from_seconds(10.0), Time::from_seconds(0.0));
+//!
+//! let velocity = distance / time;
+//! let formatted = format!("{:.1}", velocity.display(LinearVelocity::as_meters_per_second, "m/s"));
+//! assert_eq!(formatted, "10.0 ± 0.2 m/s");
+//! ```
+use std::fmt;
+
+use crate::dimension::Dimension;
+
+/// A value of dimension `D` with a standard uncertainty of the same dimension.
+#[derive(Debug, Clone, Copy)]
+pub struct Measured<D> {
+    value: D,
+    uncertainty: D,
+}
+
+impl<D: Dimension> Measured<D> {
+    /// Creates a measurement from its value and standard uncertainty, the sign of the uncertainty is ignored.
+    pub fn new(value: D, uncertainty: D) -> Self {
+        Self {
+            value,
+            uncertainty: D::from_base_units(uncertainty.as_base_units().abs()),
+        }
+    }
+
+    /// Creates a measurement with no uncertainty.
+    pub fn exact(value: D) -> Self {
+        Self::new(value, D::from_base_units(0.0))
+    }
+
+    pub fn value(&self) -> &D {
+        &self.value
+    }
+
+    pub fn uncertainty(&self) -> &D {
+        &self.uncertainty
+    }
+
+    /// The uncertainty as a fraction of the magnitude of the value.
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty.as_base_units() / self.value.as_base_units().abs()
+    }
+
+    /// Adds two measurements whose errors have the correlation coefficient `correlation`, between -1 and 1.
+    ///
+    /// # Panics
+    /// Panics if `correlation` is not between -1 and 1.
+    pub fn add_correlated(self, other: Self, correlation: f64) -> Self {
+        let (a, b) = (self.uncertainty.as_base_units(), other.uncertainty.as_base_units());
+        Self::new(
+            D::from_base_units(self.value.as_base_units() + other.value.as_base_units()),
+            D::from_base_units(combine(a, b, correlation)),
+        )
+    }
+
+    /// Subtracts two measurements whose errors have the correlation coefficient `correlation`, between -1 and 1.
+    ///
+    /// Subtracting a measurement from itself with a correlation of 1 leaves no uncertainty.
+    ///
+    /// # Panics
+    /// Panics if `correlation` is not between -1 and 1.
+    pub fn sub_correlated(self, other: Self, correlation: f64) -> Self {
+        let (a, b) = (self.uncertainty.as_base_units(), -other.uncertainty.as_base_units());
+        Self::new(
+            D::from_base_units(self.value.as_base_units() - other.value.as_base_units()),
+            D::from_base_units(combine(a, b, correlation)),
+        )
+    }
+
+    /// Multiplies two measurements of different dimensions whose errors have the correlation coefficient `correlation`, between -1 and 1.
+    ///
+    /// # Panics
+    /// Panics if `correlation` is not between -1 and 1.
+    pub fn mul_correlated<R, O>(self, rhs: Measured<R>, correlation: f64) -> Measured<O>
+    where
+        D: std::ops::Mul<R, Output = O>,
+        R: Dimension,
+        O: Dimension,
+    {
+        let (l, r) = (self.value.as_base_units(), rhs.value.as_base_units());
+        let a = r * self.uncertainty.as_base_units();
+        let b = l * rhs.uncertainty.as_base_units();
+        Measured::new(self.value * rhs.value, O::from_base_units(combine(a, b, correlation)))
+    }
+
+    /// Divides two measurements of different dimensions whose errors have the correlation coefficient `correlation`, between -1 and 1.
+    ///
+    /// # Panics
+    /// Panics if `correlation` is not between -1 and 1.
+    pub fn div_correlated<R, O>(self, rhs: Measured<R>, correlation: f64) -> Measured<O>
+    where
+        D: std::ops::Div<R, Output = O>,
+        R: Dimension,
+        O: Dimension,
+    {
+        let (l, r) = (self.value.as_base_units(), rhs.value.as_base_units());
+        let a = self.uncertainty.as_base_units() / r;
+        let b = -l * rhs.uncertainty.as_base_units() / (r * r);
+        Measured::new(self.value / rhs.value, O::from_base_units(combine(a, b, correlation)))
+    }
+
+    /// Formats the measurement in the unit given by `unit`, ie `LinearVelocity::as_meters_per_second`, labelled with `symbol`.
+    ///
+    /// The precision of the formatter, if any, is applied to both the value and the uncertainty.
+    pub fn display<'a>(&'a self, unit: fn(&D) -> f64, symbol: &'a str) -> MeasuredDisplay<'a> {
+        MeasuredDisplay {
+            value: unit(&self.value),
+            uncertainty: unit(&self.uncertainty).abs(),
+            symbol,
+        }
+    }
+}
+
+/// Combines two signed first order error contributions with the correlation coefficient `correlation`.
+///
+/// # Panics
+/// Panics if `correlation` is not between -1 and 1, as the combined variance could then be negative.
+fn combine(a: f64, b: f64, correlation: f64) -> f64 {
+    assert!((-1.0..=1.0).contains(&correlation), "correlation coefficients must be between -1 and 1");
+    // The variance is at least (|a| - |b|)², so the clamp only absorbs rounding when the contributions cancel.
+    (a * a + b * b + 2.0 * correlation * a * b).max(0.0).sqrt()
+}
+
+/// A measurement formatted as `value ± uncertainty symbol`, created by `Measured::display`.
+pub struct MeasuredDisplay<'a> {
+    value: f64,
+    uncertainty: f64,
+    symbol: &'a str,
+}
+
+impl fmt::Display for MeasuredDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} ± {:.*} {}", precision, self.value, precision, self.uncertainty, self.symbol),
+            None => write!(f, "{} ± {} {}", self.value, self.uncertainty, self.symbol),
+        }
+    }
+}
+
+impl<D: Dimension> std::ops::Add for Measured<D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.add_correlated(other, 0.0)
+    }
+}
+
+impl<D: Dimension> std::ops::Sub for Measured<D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.sub_correlated(other, 0.0)
+    }
+}
+
+impl<D: Dimension> std::ops::Mul<f64> for Measured<D> {
+    type Output = Self;
+
+    fn mul(self, scalar: f64) -> Self {
+        Self::new(
+            D::from_base_units(self.value.as_base_units() * scalar),
+            D::from_base_units(self.uncertainty.as_base_units() * scalar),
+        )
+    }
+}
+
+impl<D: Dimension> std::ops::Div<f64> for Measured<D> {
+    type Output = Self;
+
+    fn div(self, scalar: f64) -> Self {
+        Self::new(
+            D::from_base_units(self.value.as_base_units() / scalar),
+            D::from_base_units(self.uncertainty.as_base_units() / scalar),
+        )
+    }
+}
+
+impl<L, R, O> std::ops::Mul<Measured<R>> for Measured<L>
+where
+    L: Dimension + std::ops::Mul<R, Output = O>,
+    R: Dimension,
+    O: Dimension,
+{
+    type Output = Measured<O>;
+
+    fn mul(self, rhs: Measured<R>) -> Measured<O> {
+        self.mul_correlated(rhs, 0.0)
+    }
+}
+
+impl<L, R, O> std::ops::Div<Measured<R>> for Measured<L>
+where
+    L: Dimension + std::ops::Div<R, Output = O>,
+    R: Dimension,
+    O: Dimension,
+{
+    type Output = Measured<O>;
+
+    fn div(self, rhs: Measured<R>) -> Measured<O> {
+        self.div_correlated(rhs, 0.0)
+    }
+}
+
+impl<D: Dimension> PartialEq for Measured<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.as_base_units() == other.value.as_base_units()
+            && self.uncertainty.as_base_units() == other.uncertainty.as_base_units()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn distance(value: f64, uncertainty: f64) -> Measured<Distance> {
+        Measured::new(Distance::from_meters(value), Distance::from_meters(uncertainty))
+    }
+
+    #[test]
+    fn test_add_and_sub_in_quadrature() {
+        let sum = distance(10.0, 3.0) + distance(5.0, 4.0);
+        assert!((sum.value().as_meters() - 15.0).abs() < EPSILON);
+        assert!((sum.uncertainty().as_meters() - 5.0).abs() < EPSILON);
+
+        let difference = distance(10.0, 3.0) - distance(5.0, 4.0);
+        assert!((difference.value().as_meters() - 5.0).abs() < EPSILON);
+        assert!((difference.uncertainty().as_meters() - 5.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_scalar_operations() {
+        let scaled = distance(10.0, 0.5) * -2.0;
+        assert!((scaled.value().as_meters() + 20.0).abs() < EPSILON);
+        assert!((scaled.uncertainty().as_meters() - 1.0).abs() < EPSILON);
+
+        let divided = distance(10.0, 0.5) / 4.0;
+        assert!((divided.uncertainty().as_meters() - 0.125).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_cross_dimension_operations() {
+        let time = Measured::new(Time::from_seconds(10.0), Time::from_seconds(0.4));
+        let velocity = distance(100.0, 3.0) / time;
+        assert!((velocity.value().as_meters_per_second() - 10.0).abs() < EPSILON);
+        assert!((velocity.relative_uncertainty() - 0.05).abs() < EPSILON);
+
+        let force = Measured::new(Force::from_newtons(20.0), Force::from_newtons(0.6));
+        let torque = force * distance(2.0, 0.08);
+        assert!((torque.value().as_newton_meters() - 40.0).abs() < EPSILON);
+        assert!((torque.relative_uncertainty() - 0.05).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_correlated_errors() {
+        let a = distance(10.0, 1.0);
+        assert!(a.sub_correlated(a, 1.0).uncertainty().as_meters().abs() < EPSILON);
+        assert!((a.add_correlated(a, 1.0).uncertainty().as_meters() - 2.0).abs() < EPSILON);
+
+        let power = Measured::new(Power::from_watts(100.0), Power::from_watts(10.0));
+        let voltage = Measured::new(ElectricPotential::from_volts(10.0), ElectricPotential::from_volts(1.0));
+        let current = power.div_correlated(voltage, 1.0);
+        assert!(current.uncertainty().as_amperes().abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_correlations_outside_the_unit_interval_are_rejected() {
+        let a = distance(10.0, 1.0);
+        let time = Measured::new(Time::from_seconds(2.0), Time::from_seconds(0.1));
+        assert!(std::panic::catch_unwind(|| a.add_correlated(a, 1.5)).is_err());
+        assert!(std::panic::catch_unwind(|| a.sub_correlated(a, -1.01)).is_err());
+        assert!(std::panic::catch_unwind(|| a.mul_correlated(a, 2.0)).is_err());
+        assert!(std::panic::catch_unwind(|| a.div_correlated(time, f64::NAN)).is_err());
+        assert!(a.add_correlated(a, -1.0).uncertainty().as_meters().abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_display() {
+        let velocity = Measured::new(LinearVelocity::from_meters_per_second(10.0), LinearVelocity::from_meters_per_second(0.2));
+        assert_eq!(format!("{:.1}", velocity.display(LinearVelocity::as_meters_per_second, "m/s")), "10.0 ± 0.2 m/s");
+        assert_eq!(format!("{}", velocity.display(LinearVelocity::as_meters_per_second, "m/s")), "10 ± 0.2 m/s");
+    }
+}
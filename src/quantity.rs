@@ -0,0 +1,489 @@
+//! This module provides `Quantity`, a dynamically dimensioned value that keeps track of its SI dimension and kind at runtime.
+//!
+//! Any unit struct tagged with `create_kind!` can be converted into a `Quantity`. Quantities of the same SI dimension can still be different kinds,
+//! like `Torque` and `Energy`, so adding or comparing quantities checks both, and converting a quantity back into a unit struct only succeeds
+//! for a quantity of that kind, or for a quantity created with `Quantity::new`, which has no kind yet.
+//!
+//! Multiplying or dividing quantities only gives a kind through a declared operation, so the product of a `Torque` and an `Angle` is an `Energy`.
+//! The operations between the built-in dimensions are always declared, and a kind can declare its own with `create_relationships!`, passing
+//! the table to `create_kind!`. Scaling by a dimensionless quantity keeps the kind of the other operand, and any other product or quotient has
+//! no declared kind, and can't be converted into a unit struct, even one of the same dimension.
+//!
+//! Kinds are told apart by their type, rather than by their name, so a kind defined outside this crate is never mistaken for a built-in kind
+//! with the same name.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusty_units::quantity::{KindError, Quantity};
+//! use rusty_units::units::{Angle, Energy, Pressure, Torque, Volume};
+//!
+//! let torque = Quantity::from(Torque::from_newton_meters(10.0));
+//! let energy = Quantity::from(Energy::from_joules(5.0));
+//! assert_eq!(torque.dimension(), energy.dimension());
+//!
+//! // Torque and energy are both N·m, but they are different kinds of quantity.
+//! assert!(matches!(torque.checked_add(energy), Err(KindError::KindMismatch { .. })));
+//! assert!(torque.into_kind::<Energy>().is_err());
+//!
+//! // Work done by a torque through an angle is an energy.
+//! let work = torque * Quantity::from(Angle::from_radians(0.5));
+//! assert_eq!(work.kind(), Some("Energy"));
+//! assert_eq!(work.into_kind::<Energy>().unwrap().as_joules(), 5.0);
+//!
+//! // A pressure times a volume has the dimension of energy, but isn't a declared operation, so it has no kind.
+//! let product = Quantity::from(Pressure::from_pascals(2.0)) * Quantity::from(Volume::from_cubic_meters(3.0));
+//! assert_eq!(product.into_kind::<Energy>(), Err(KindError::UndeclaredKind { expected: "Energy" }));
+//! ```
+use std::any::TypeId;
+use std::fmt;
+
+use crate::dimension::{Kind, SiDimension};
+use crate::units::KIND_OPERATIONS;
+
+/// A value in SI base units with a runtime SI dimension, and the kind of quantity it is, if known.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity {
+    value: f64,
+    dimension: SiDimension,
+    kind: Tag,
+}
+
+/// What is known about the kind of a quantity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tag {
+    /// Created without a kind, which can be given one explicitly.
+    Untagged,
+    Kind(KindId),
+    /// The product or quotient of quantities with no declared operation between them, which can't be given a kind.
+    Undeclared,
+}
+
+/// The identity of a kind, which compares by the type of the kind, so kinds with the same name from different crates stay distinct.
+#[derive(Clone, Copy)]
+pub struct KindId {
+    name: &'static str,
+    dimension: SiDimension,
+    type_id: fn() -> TypeId,
+    operations: fn() -> &'static [KindOperation],
+}
+
+impl KindId {
+    pub const fn of<K: Kind>() -> Self {
+        Self {
+            name: K::NAME,
+            dimension: K::SI_DIMENSION,
+            type_id: TypeId::of::<K>,
+            operations: K::operations,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn dimension(&self) -> SiDimension {
+        self.dimension
+    }
+}
+
+impl PartialEq for KindId {
+    fn eq(&self, other: &Self) -> bool {
+        (self.type_id)() == (other.type_id)()
+    }
+}
+
+impl Eq for KindId {}
+
+impl fmt::Debug for KindId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// The operator of a `KindOperation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Mul,
+    Div,
+}
+
+/// An operation declared between two kinds, which gives the kind of the product or quotient of two quantities.
+///
+/// Operations are generated by `create_relationships!`. A `None` operand or result is a dimensionless `f64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KindOperation {
+    lhs: Option<KindId>,
+    operator: Operator,
+    rhs: Option<KindId>,
+    result: Option<KindId>,
+}
+
+impl KindOperation {
+    pub const fn new(lhs: Option<KindId>, operator: Operator, rhs: Option<KindId>, result: Option<KindId>) -> Self {
+        Self { lhs, operator, rhs, result }
+    }
+}
+
+/// An error from combining or converting quantities that don't match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KindError {
+    /// The quantities have different SI dimensions.
+    DimensionMismatch { expected: SiDimension, found: SiDimension },
+    /// The quantities have the same SI dimension, but are different kinds.
+    KindMismatch { expected: &'static str, found: &'static str },
+    /// The quantity is a product or quotient with no declared kind.
+    UndeclaredKind { expected: &'static str },
+}
+
+impl fmt::Display for KindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KindError::DimensionMismatch { expected, found } => {
+                write!(f, "expected a quantity of dimension {expected}, found {found}")
+            }
+            KindError::KindMismatch { expected, found } => {
+                write!(f, "expected a quantity of kind {expected}, found {found}")
+            }
+            KindError::UndeclaredKind { expected } => {
+                write!(f, "expected a quantity of kind {expected}, found a product or quotient with no declared kind")
+            }
+        }
+    }
+}
+
+impl std::error::Error for KindError {}
+
+impl Quantity {
+    /// Creates a quantity with no kind from a value in SI base units.
+    pub fn new(value: f64, dimension: SiDimension) -> Self {
+        Self { value, dimension, kind: Tag::Untagged }
+    }
+
+    /// The value in SI base units.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn dimension(&self) -> SiDimension {
+        self.dimension
+    }
+
+    /// The name of the kind of the quantity, or `None` for quantities with no kind.
+    pub fn kind(&self) -> Option<&'static str> {
+        match self.kind {
+            Tag::Kind(kind) => Some(kind.name),
+            Tag::Untagged | Tag::Undeclared => None,
+        }
+    }
+
+    /// Checks that two quantities can be added or compared, and returns the kind of the result.
+    fn check_compatible(&self, other: &Self) -> Result<Tag, KindError> {
+        if self.dimension != other.dimension {
+            return Err(KindError::DimensionMismatch { expected: self.dimension, found: other.dimension });
+        }
+        match (self.kind, other.kind) {
+            (Tag::Kind(expected), Tag::Kind(found)) if expected != found => {
+                Err(KindError::KindMismatch { expected: expected.name, found: found.name })
+            }
+            (Tag::Kind(expected), Tag::Undeclared) | (Tag::Undeclared, Tag::Kind(expected)) => {
+                Err(KindError::UndeclaredKind { expected: expected.name })
+            }
+            (Tag::Untagged, kind) => Ok(kind),
+            (kind, _) => Ok(kind),
+        }
+    }
+
+    /// The operand the quantity is in a `KindOperation`, where `Some(None)` is a dimensionless `f64`, or `None` if it can't be one.
+    fn operand(&self) -> Option<Option<KindId>> {
+        match self.kind {
+            Tag::Kind(kind) => Some(Some(kind)),
+            Tag::Untagged if self.dimension == SiDimension::DIMENSIONLESS => Some(None),
+            Tag::Untagged | Tag::Undeclared => None,
+        }
+    }
+
+    /// Finds the kind of the result of an operation between two quantities, from the operations between the built-in kinds,
+    /// and the operations declared by the kinds of either operand.
+    fn result_kind(&self, operator: Operator, other: &Self) -> Tag {
+        if let (Some(lhs), Some(rhs)) = (self.operand(), other.operand()) {
+            let declared_by = |kind: Option<KindId>| kind.map_or(&[][..], |kind| (kind.operations)());
+            let declared = [KIND_OPERATIONS, declared_by(lhs), declared_by(rhs)]
+                .into_iter()
+                .flatten()
+                .find(|operation| operation.lhs == lhs && operation.operator == operator && operation.rhs == rhs);
+            if let Some(operation) = declared {
+                return operation.result.map_or(Tag::Untagged, Tag::Kind);
+            }
+        }
+        match (self.kind, other.kind) {
+            (Tag::Untagged, Tag::Untagged) => Tag::Untagged,
+            _ if other.dimension == SiDimension::DIMENSIONLESS => self.kind,
+            _ if operator == Operator::Mul && self.dimension == SiDimension::DIMENSIONLESS => other.kind,
+            _ => Tag::Undeclared,
+        }
+    }
+
+    /// Adds two quantities, failing if they have different dimensions or kinds.
+    pub fn checked_add(self, other: Self) -> Result<Self, KindError> {
+        let kind = self.check_compatible(&other)?;
+        Ok(Self { value: self.value + other.value, dimension: self.dimension, kind })
+    }
+
+    /// Subtracts two quantities, failing if they have different dimensions or kinds.
+    pub fn checked_sub(self, other: Self) -> Result<Self, KindError> {
+        let kind = self.check_compatible(&other)?;
+        Ok(Self { value: self.value - other.value, dimension: self.dimension, kind })
+    }
+
+    /// Compares two quantities, failing if they have different dimensions or kinds.
+    pub fn checked_partial_cmp(&self, other: &Self) -> Result<Option<std::cmp::Ordering>, KindError> {
+        self.check_compatible(other)?;
+        Ok(self.value.partial_cmp(&other.value))
+    }
+
+    /// Explicitly tags a quantity created with no kind as the kind `K`.
+    ///
+    /// A product or quotient with no declared kind can't be tagged, to tag one anyway, create a new quantity from its value and dimension.
+    pub fn with_kind<K: Kind>(self) -> Result<Self, KindError> {
+        if self.dimension != K::SI_DIMENSION {
+            return Err(KindError::DimensionMismatch { expected: K::SI_DIMENSION, found: self.dimension });
+        }
+        match self.kind {
+            Tag::Kind(found) if found != KindId::of::<K>() => Err(KindError::KindMismatch { expected: K::NAME, found: found.name }),
+            Tag::Undeclared => Err(KindError::UndeclaredKind { expected: K::NAME }),
+            _ => Ok(Self { kind: Tag::Kind(KindId::of::<K>()), ..self }),
+        }
+    }
+
+    /// Converts the quantity into the unit struct of kind `K`, failing if it has a different dimension, is already another kind,
+    /// or has no declared kind.
+    pub fn into_kind<K: Kind>(self) -> Result<K, KindError> {
+        self.with_kind::<K>().map(|quantity| K::from_base_units(quantity.value))
+    }
+}
+
+impl<K: Kind> From<K> for Quantity {
+    fn from(value: K) -> Self {
+        Self {
+            value: value.as_base_units(),
+            dimension: K::SI_DIMENSION,
+            kind: Tag::Kind(KindId::of::<K>()),
+        }
+    }
+}
+
+impl std::ops::Mul for Quantity {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            value: self.value * other.value,
+            dimension: self.dimension.mul(other.dimension),
+            kind: self.result_kind(Operator::Mul, &other),
+        }
+    }
+}
+
+impl std::ops::Div for Quantity {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self {
+            value: self.value / other.value,
+            dimension: self.dimension.div(other.dimension),
+            kind: self.result_kind(Operator::Div, &other),
+        }
+    }
+}
+
+impl std::ops::Mul<f64> for Quantity {
+    type Output = Self;
+
+    fn mul(self, scalar: f64) -> Self {
+        Self { value: self.value * scalar, ..self }
+    }
+}
+
+impl std::ops::Div<f64> for Quantity {
+    type Output = Self;
+
+    fn div(self, scalar: f64) -> Self {
+        Self { value: self.value / scalar, ..self }
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind() {
+            Some(kind) => write!(f, "{} {} ({kind})", self.value, self.dimension),
+            None => write!(f, "{} {}", self.value, self.dimension),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::*;
+
+    #[test]
+    fn test_same_kind_arithmetic() {
+        let a = Quantity::from(Distance::from_meters(1.0));
+        let b = Quantity::from(Distance::from_meters(2.0));
+        let sum = a.checked_add(b).unwrap();
+        assert_eq!(sum.kind(), Some("Distance"));
+        assert_eq!(sum.into_kind::<Distance>().unwrap().as_meters(), 3.0);
+        assert_eq!(b.checked_sub(a).unwrap().value(), 1.0);
+        assert_eq!(a.checked_partial_cmp(&b), Ok(Some(std::cmp::Ordering::Less)));
+    }
+
+    #[test]
+    fn test_kinds_sharing_a_dimension_are_rejected() {
+        let torque = Quantity::from(Torque::from_newton_meters(1.0));
+        let energy = Quantity::from(Energy::from_joules(1.0));
+        assert_eq!(
+            torque.checked_add(energy),
+            Err(KindError::KindMismatch { expected: "Torque", found: "Energy" })
+        );
+        assert!(torque.into_kind::<Energy>().is_err());
+
+        let frequency = Quantity::from(Frequency::from_hertz(1.0));
+        let angular_velocity = Quantity::from(AngularVelocity::from_radians_per_second(1.0));
+        assert_eq!(frequency.dimension(), angular_velocity.dimension());
+        assert!(frequency.checked_sub(angular_velocity).is_err());
+        assert!(frequency.checked_partial_cmp(&angular_velocity).is_err());
+    }
+
+    #[test]
+    fn test_dimensions_are_checked() {
+        let distance = Quantity::from(Distance::from_meters(1.0));
+        let time = Quantity::from(Time::from_seconds(1.0));
+        assert!(matches!(distance.checked_add(time), Err(KindError::DimensionMismatch { .. })));
+        assert!(matches!(distance.into_kind::<Time>(), Err(KindError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_declared_operations_give_kinds() {
+        let velocity = Quantity::from(Distance::from_meters(10.0)) / Quantity::from(Time::from_seconds(2.0));
+        assert_eq!(velocity.kind(), Some("LinearVelocity"));
+        assert_eq!(velocity.into_kind::<LinearVelocity>().unwrap().as_meters_per_second(), 5.0);
+
+        let work = Quantity::from(Torque::from_newton_meters(3.0)) * Quantity::from(Angle::from_radians(2.0));
+        assert_eq!(work.kind(), Some("Energy"));
+        assert!(work.checked_add(Quantity::from(Energy::from_joules(1.0))).is_ok());
+        assert_eq!((work / Quantity::from(Angle::from_radians(2.0))).kind(), Some("Torque"));
+
+        let frequency = Quantity::new(1.0, SiDimension::DIMENSIONLESS) / Quantity::from(Time::from_seconds(0.5));
+        assert_eq!(frequency.kind(), Some("Frequency"));
+        let cycles = frequency * Quantity::from(Time::from_seconds(3.0));
+        assert_eq!((cycles.kind(), cycles.value()), (None, 6.0));
+    }
+
+    #[test]
+    fn test_undeclared_kinds_are_rejected() {
+        let torque = Quantity::from(Torque::from_newton_meters(2.0));
+
+        // Scaling by a dimensionless quantity keeps the kind, rather than relabelling the torque as an energy.
+        let scaled = torque * Quantity::from(Information::from_bits(1.0));
+        assert_eq!(scaled.kind(), Some("Torque"));
+        assert!(matches!(scaled.into_kind::<Energy>(), Err(KindError::KindMismatch { .. })));
+        let scaled = torque * Quantity::new(1.0, SiDimension::DIMENSIONLESS);
+        assert!(matches!(scaled.into_kind::<Energy>(), Err(KindError::KindMismatch { .. })));
+        assert_eq!((torque / Quantity::new(2.0, SiDimension::DIMENSIONLESS)).kind(), Some("Torque"));
+
+        let product = Quantity::from(Pressure::from_pascals(2.0)) * Quantity::from(Volume::from_cubic_meters(3.0));
+        assert_eq!(product.kind(), None);
+        assert_eq!(product.into_kind::<Energy>(), Err(KindError::UndeclaredKind { expected: "Energy" }));
+        assert_eq!(product.with_kind::<Torque>(), Err(KindError::UndeclaredKind { expected: "Torque" }));
+        assert!(product.checked_add(Quantity::from(Energy::from_joules(1.0))).is_err());
+        assert!(product.checked_add(product).is_ok());
+        assert!(Quantity::new(product.value(), product.dimension()).into_kind::<Energy>().is_ok());
+    }
+
+    #[test]
+    fn test_declared_operations_are_dimensionally_consistent() {
+        fn product<L: Kind, R: Kind, O: Kind>() -> bool {
+            L::SI_DIMENSION.mul(R::SI_DIMENSION) == O::SI_DIMENSION
+        }
+        fn quotient<L: Kind, R: Kind, O: Kind>() -> bool {
+            L::SI_DIMENSION.div(R::SI_DIMENSION) == O::SI_DIMENSION
+        }
+        assert!(quotient::<Distance, Time, LinearVelocity>());
+        assert!(product::<Force, Distance, Torque>());
+        assert!(product::<Torque, Angle, Energy>());
+        assert!(product::<Mass, LinearAcceleration, Force>());
+        assert!(product::<ElectricPotential, Current, Power>());
+        assert!(product::<Current, Resistance, ElectricPotential>());
+        assert!(product::<Resistance, Capacitance, Time>());
+        assert!(product::<TorqueConstant, Current, Torque>());
+        assert!(product::<VelocityConstant, ElectricPotential, AngularVelocity>());
+        assert!(product::<Pressure, VolumetricFlowRate, Power>());
+        assert!(product::<Power, ThermalResistance, TemperatureDelta>());
+        assert!(quotient::<LuminousFlux, Area, Illuminance>());
+        assert!(quotient::<Mass, MolarMass, AmountOfSubstance>());
+        assert!(quotient::<Energy, Mass, AbsorbedDose>());
+        assert!(product::<LinearVelocity, LinearVelocity, SpecificEnergy>());
+        assert!(product::<Area, Distance, Volume>());
+    }
+
+    #[test]
+    fn test_every_built_in_operation_is_dimensionally_consistent() {
+        let dimension = |kind: Option<KindId>| kind.map_or(SiDimension::DIMENSIONLESS, |kind| kind.dimension());
+        for operation in KIND_OPERATIONS {
+            let (lhs, rhs) = (dimension(operation.lhs), dimension(operation.rhs));
+            let result = match operation.operator {
+                Operator::Mul => lhs.mul(rhs),
+                Operator::Div => lhs.div(rhs),
+            };
+            assert_eq!(result, dimension(operation.result), "{operation:?}");
+        }
+    }
+
+    // Kinds declared the way a crate using this one would, with names of their own and a name shared with a built-in kind.
+    #[allow(dead_code)]
+    mod downstream {
+        use crate::units::{Distance, Time};
+        use crate::{create_kind, create_relationships, create_unit};
+
+        create_unit!(Pace, seconds_per_meter => 1.0, minutes_per_kilometer => 60.0 / 1000.0);
+        create_kind!(Pace, length: -1, time: 1; operations: OPERATIONS);
+
+        create_unit!(Torque, newton_meters => 1.0);
+        create_kind!(Torque, length: 2, mass: 1, time: -2);
+
+        create_relationships! {
+            pub const OPERATIONS;
+            single Pace * Distance => Time,
+            single Distance * Pace => Time,
+        }
+    }
+
+    #[test]
+    fn test_kinds_declared_outside_the_built_in_dimensions() {
+        let pace = Quantity::from(downstream::Pace::from_minutes_per_kilometer(5.0));
+        let time = pace * Quantity::from(Distance::from_kilometers(2.0));
+        assert_eq!(time.kind(), Some("Time"));
+        assert!((time.into_kind::<Time>().unwrap().as_minutes() - 10.0).abs() < 1e-9);
+        assert_eq!((Quantity::from(Distance::from_meters(100.0)) * pace).kind(), Some("Time"));
+        assert_eq!((Quantity::from(Time::from_seconds(1.0)) / Quantity::from(Distance::from_meters(1.0))).kind(), None);
+
+        // A kind sharing a name with a built-in kind is still a different kind, and doesn't take its operations.
+        let torque = Quantity::from(downstream::Torque::from_newton_meters(2.0));
+        assert_eq!(torque.kind(), Some("Torque"));
+        assert_eq!(
+            torque.checked_add(Quantity::from(Torque::from_newton_meters(1.0))),
+            Err(KindError::KindMismatch { expected: "Torque", found: "Torque" })
+        );
+        assert!(torque.into_kind::<Torque>().is_err());
+        let product = torque * Quantity::from(Angle::from_radians(1.0));
+        assert_eq!(product.into_kind::<Energy>(), Err(KindError::KindMismatch { expected: "Energy", found: "Torque" }));
+        assert_eq!(product.into_kind::<downstream::Torque>().unwrap().as_newton_meters(), 2.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let quantity = Quantity::from(Torque::from_newton_meters(2.0));
+        assert_eq!(quantity.to_string(), "2 m^2·kg·s^-2 (Torque)");
+    }
+}
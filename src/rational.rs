@@ -0,0 +1,187 @@
+//! Exact rational arithmetic for unit conversions, enabled with the `rational` feature.
+//!
+//! Every unit created with `create_unit!` gets an exact companion struct, named after the unit with an `Exact` prefix,
+//! ie `ExactDistance` for `Distance`. It stores its base magnitude as a [`BigRational`], and its conversion factors are
+//! parsed from the text of the unit definitions, so a definition like `feet => 3048.0 / 10000.0` or `pound_feet => 3389544870828501.0 / 2500000000000000.0`
+//! is represented exactly rather than as the nearest `f64`.
+//!
+//! Conversions to and from the `f64` unit structs happen only at the edges. Converting an exact struct to its `f64` struct uses `From`, rounding
+//! to the nearest `f64`. The other direction uses `TryFrom`, which fails with a `NotFiniteError` for NaN and infinite values.
+//!
+//! Definitions that are not plain numeric literals, like `(2.0 * PI)`, can't be recovered exactly, and fall back to the exact value of their `f64` approximation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusty_units::rational::BigRational;
+//! use rusty_units::units::{Distance, ExactDistance};
+//!
+//! let inches = ExactDistance::from_inches(BigRational::from_integer(63360.into()));
+//! let feet = ExactDistance::from_feet(inches.as_feet());
+//! let miles = ExactDistance::from_miles(feet.as_miles());
+//!
+//! assert_eq!(miles.as_miles(), BigRational::from_integer(1.into()));
+//! assert_eq!(miles.as_meters(), BigRational::new(1609344.into(), 1000.into()));
+//! assert_eq!(Distance::from(miles).as_meters(), 1609.344);
+//! ```
+use std::fmt;
+
+pub use num_bigint::BigInt;
+pub use num_rational::BigRational;
+use num_traits::Zero;
+
+/// An error from converting an `f64` that is NaN or infinite, which has no exact rational value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NotFiniteError(pub f64);
+
+impl fmt::Display for NotFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot represent the non-finite value {} exactly", self.0)
+    }
+}
+
+impl std::error::Error for NotFiniteError {}
+
+/// Parses a conversion factor from the source text of its definition.
+///
+/// Decimal literals, with an optional fraction, exponent, `_` separators, and `f64` suffix, are parsed exactly.
+/// Anything else falls back to the exact value of `approximation`.
+///
+/// # Errors
+/// Returns a `NotFiniteError` if the text can't be parsed and `approximation` is not finite. `create_unit!` checks that every
+/// factor is finite when the unit is compiled, so this can't happen for the factors of its exact companions.
+pub fn factor(text: &str, approximation: f64) -> Result<BigRational, NotFiniteError> {
+    parse_decimal(text).map_or_else(|| from_f64(approximation), Ok)
+}
+
+fn parse_decimal(text: &str) -> Option<BigRational> {
+    let text: String = text.trim().trim_end_matches("f64").chars().filter(|c| *c != '_').collect();
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(index) => (&text[..index], text[index + 1..].parse::<i32>().ok()?),
+        None => (text.as_str(), 0),
+    };
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if whole.is_empty() || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let digits: BigInt = format!("{whole}{fraction}").parse().ok()?;
+    let scale = exponent - i32::try_from(fraction.len()).ok()?;
+    let power = num_traits::pow(BigInt::from(10), scale.unsigned_abs() as usize);
+    if scale >= 0 {
+        Some(BigRational::from_integer(digits * power))
+    } else {
+        Some(BigRational::new(digits, power))
+    }
+}
+
+/// Converts a rational to the nearest `f64`, saturating to infinity when it is out of range.
+pub fn to_f64(value: &BigRational) -> f64 {
+    num_traits::ToPrimitive::to_f64(value).unwrap_or(if value.is_zero() {
+        0.0
+    } else if value > &BigRational::zero() {
+        f64::INFINITY
+    } else {
+        f64::NEG_INFINITY
+    })
+}
+
+/// Converts an `f64` to the rational with exactly the same value, failing if `value` is not finite.
+pub fn from_f64(value: f64) -> Result<BigRational, NotFiniteError> {
+    BigRational::from_float(value).ok_or(NotFiniteError(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::*;
+
+    fn ratio(numerator: i64, denominator: i64) -> BigRational {
+        BigRational::new(numerator.into(), denominator.into())
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(factor("0.3048", 0.0), Ok(ratio(3048, 10000)));
+        assert_eq!(factor("1_000_000.0", 0.0), Ok(ratio(1_000_000, 1)));
+        assert_eq!(factor("1e-5", 0.0), Ok(ratio(1, 100_000)));
+        assert_eq!(factor("2.5E3", 0.0), Ok(ratio(2500, 1)));
+        assert_eq!(factor("(2.0 * PI)", 0.5), Ok(ratio(1, 2)));
+        assert_eq!(factor("f64::INFINITY", f64::INFINITY), Err(NotFiniteError(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_gravitational_units_are_exact() {
+        let kilogram_force = ExactForce::from_kilograms_force(ratio(1, 1)).as_newtons();
+        assert_eq!(kilogram_force, ratio(980665, 100000));
+        assert_eq!(to_f64(&kilogram_force), crate::constants::STANDARD_GRAVITY.as_meters_per_second_squared());
+        assert_eq!(
+            ExactForce::from_pounds_force(ratio(1, 1)).as_newtons(),
+            ExactMass::from_pounds(ratio(1, 1)).as_kilograms() * ratio(980665, 100000)
+        );
+    }
+
+    #[test]
+    fn test_derived_units_are_exact() {
+        let pressure = ExactPressure::from_pounds_force_per_square_foot(ratio(144, 1));
+        assert_eq!(pressure, ExactPressure::from_psi(ratio(1, 1)));
+        assert_eq!(
+            pressure.as_pascals(),
+            ExactForce::from_pounds_force(ratio(144, 1)).as_newtons() / ExactArea::from_square_feet(ratio(1, 1)).as_square_meters()
+        );
+        assert_eq!(ExactVolumetricFlowRate::from_liters_per_second(ratio(60, 1)).as_liters_per_minute(), ratio(3600, 1));
+        assert_eq!(ExactMassFlowRate::from_kilograms_per_minute(ratio(1, 1)).as_kilograms_per_second(), ratio(1, 60));
+    }
+
+    #[test]
+    fn test_exact_distance_chain() {
+        let inches = ExactDistance::from_inches(ratio(190080, 1));
+        let feet = ExactDistance::from_feet(inches.as_feet());
+        let miles = ExactDistance::from_miles(feet.as_miles());
+        assert_eq!(feet.as_feet(), ratio(15840, 1));
+        assert_eq!(miles.as_miles(), ratio(3, 1));
+        assert_eq!(miles.as_meters(), ratio(4828032, 1000));
+        assert_eq!(miles, inches);
+    }
+
+    #[test]
+    fn test_exact_decimal_definitions() {
+        let torque = ExactTorque::from_pound_feet(ratio(1, 1));
+        assert_eq!(torque.as_pound_inches(), ratio(12, 1));
+        assert_eq!(ExactForce::from_pounds_force(ratio(1, 1)).as_newtons(), ratio(44482216152605, 10000000000000));
+
+        // A pound foot is exactly a pound force times a foot, and a horsepower is exactly 550 pound feet per second.
+        let pound_force = ExactForce::from_pounds_force(ratio(1, 1)).as_newtons();
+        let foot = ExactDistance::from_feet(ratio(1, 1)).as_meters();
+        assert_eq!(torque.as_newton_meters(), pound_force.clone() * foot.clone());
+        assert_eq!(ExactPower::from_horsepower(ratio(1, 1)).as_watts(), pound_force.clone() * foot * ratio(550, 1));
+        let ounce_inch = ExactTorqueConstant::from_ounce_inches_per_ampere(ratio(16, 1)).as_newton_meters_per_ampere();
+        assert_eq!(ounce_inch, ExactTorque::from_pound_inches(ratio(1, 1)).as_newton_meters());
+    }
+
+    #[test]
+    fn test_exact_arithmetic() {
+        let tenth = ExactDistance::from_meters(ratio(1, 10));
+        let sum = tenth.clone() + tenth.clone() + tenth;
+        assert_eq!(sum.as_meters(), ratio(3, 10));
+        assert_eq!((sum.clone() * ratio(10, 3)).as_meters(), ratio(1, 1));
+        assert_eq!((sum.clone() / ratio(3, 1)).as_meters(), ratio(1, 10));
+        assert_eq!((sum.clone() - sum).as_meters(), ratio(0, 1));
+    }
+
+    #[test]
+    fn test_f64_edges() {
+        let exact = ExactDistance::try_from(Distance::from_meters(0.5)).unwrap();
+        assert_eq!(exact.as_meters(), ratio(1, 2));
+        assert_eq!(Distance::from(ExactDistance::from_feet(ratio(1, 1))).as_meters(), 0.3048);
+        assert_eq!(to_f64(&ratio(1, 3)), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_non_finite_values_are_rejected() {
+        assert_eq!(ExactDistance::try_from(Distance::from_meters(f64::INFINITY)), Err(NotFiniteError(f64::INFINITY)));
+        assert_eq!(ExactTime::try_from(Time::from_seconds(f64::NEG_INFINITY)), Err(NotFiniteError(f64::NEG_INFINITY)));
+        assert!(ExactMass::try_from(Mass::from_kilograms(f64::NAN)).is_err());
+        assert_eq!(NotFiniteError(f64::INFINITY).to_string(), "cannot represent the non-finite value inf exactly");
+    }
+}
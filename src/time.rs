@@ -0,0 +1,398 @@
+//! This module connects `Time` with the time types of the standard library, and provides `Timestamp`, an absolute point in time.
+//!
+//! `Time` is a duration, and converts from a `std::time::Duration` to the nearest `f64` number of seconds, which keeps nanosecond precision
+//! for durations up to a few weeks, but rounds longer ones. Converting back fails for negative or non-finite times, which a `Duration`
+//! can't represent. A `Time` can be added to or subtracted from an `Instant`, in either direction if it's negative. Like the standard library's,
+//! the operators panic if the time is not finite or the result is out of range, and `checked_add` and `checked_sub` return `None` instead.
+//!
+//! A `Timestamp` is to `Time` what `Temperature` is to `TemperatureDelta`: subtracting two timestamps gives the `Time` between them, and a `Time`
+//! can be added to or subtracted from a timestamp, but adding two timestamps together is meaningless, so it isn't defined.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::{Duration, Instant};
+//! use rusty_units::time::Timestamp;
+//! use rusty_units::units::Time;
+//!
+//! let elapsed = Time::from(Duration::from_millis(1500));
+//! assert_eq!(elapsed.as_seconds(), 1.5);
+//! assert_eq!(Duration::try_from(Time::from_minutes(2.0)), Ok(Duration::from_secs(120)));
+//! assert!(Duration::try_from(Time::from_seconds(-1.0)).is_err());
+//!
+//! let start = Instant::now();
+//! let deadline = start + Time::from_seconds(30.0);
+//! assert_eq!(deadline - start, Duration::from_secs(30));
+//!
+//! let launch = Timestamp::from_unix_seconds(1_000_000_000.0);
+//! let landing = launch + Time::from_hours(2.0);
+//! assert_eq!((landing - launch).as_minutes(), 120.0);
+//! ```
+//!
+//! # Fixed-length durations and calendar arithmetic
+//!
+//! Every unit of `Time` is a fixed number of seconds, so units based on the calendar are averages: `months` is a twelfth of a Gregorian year,
+//! 30.436875 days, `gregorian_years` is 365.2425 days, `julian_years` is 365.25 days, and `years` is a common year of 365 days. Adding them to a
+//! `Timestamp` adds that many seconds, which is right for rates and physics, but won't land on the same day of a later month or year.
+//!
+//! ```rust
+//! use rusty_units::time::Timestamp;
+//! use rusty_units::units::Time;
+//!
+//! assert_eq!(Time::from_months(12.0), Time::from_gregorian_years(1.0));
+//! assert_eq!(Time::from_julian_years(1.0).as_days(), 365.25);
+//!
+//! // 2023-01-31 00:00:00 UTC plus an average month is partway through 2 March, not the end of February.
+//! let end_of_january = Timestamp::from_unix_seconds(1_675_123_200.0);
+//! let later = end_of_january + Time::from_months(1.0);
+//! assert_eq!((later - end_of_january).as_days(), 30.436875);
+//! assert!(later > Timestamp::from_unix_seconds(1_677_715_200.0));
+//! ```
+//!
+//! For calendar arithmetic, enable the `chrono` feature, which converts a `Timestamp` to and from a `chrono::DateTime<Utc>`, a `Time` to and from
+//! a `chrono::TimeDelta`, and provides `Timestamp::checked_add_months` and `Timestamp::checked_add_years`, which follow the calendar in UTC.
+use std::fmt;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::units::Time;
+
+/// An error from converting a `Time` or `Timestamp` into a standard library time type that can't represent it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeConversionError {
+    /// The time is negative, and a `Duration` can only be positive.
+    Negative,
+    /// The time is infinite or NaN.
+    NotFinite,
+    /// The time is too large to be represented.
+    Overflow,
+}
+
+impl fmt::Display for TimeConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeConversionError::Negative => write!(f, "cannot convert a negative time into a duration"),
+            TimeConversionError::NotFinite => write!(f, "cannot convert a non-finite time into a duration"),
+            TimeConversionError::Overflow => write!(f, "time is too large to be represented"),
+        }
+    }
+}
+
+impl std::error::Error for TimeConversionError {}
+
+/// Converts to the nearest `f64` number of seconds, which no longer has nanosecond precision for durations longer than a few weeks.
+impl From<Duration> for Time {
+    fn from(duration: Duration) -> Self {
+        Time::from_seconds(duration.as_secs_f64())
+    }
+}
+
+impl TryFrom<Time> for Duration {
+    type Error = TimeConversionError;
+
+    fn try_from(time: Time) -> Result<Self, TimeConversionError> {
+        let seconds = time.as_seconds();
+        if !seconds.is_finite() {
+            return Err(TimeConversionError::NotFinite);
+        }
+        if seconds < 0.0 {
+            return Err(TimeConversionError::Negative);
+        }
+        Duration::try_from_secs_f64(seconds).map_err(|_| TimeConversionError::Overflow)
+    }
+}
+
+/// Splits a time into its magnitude as a `Duration` and whether it's negative, or `None` if it's not finite or too large for a `Duration`.
+fn signed_duration(time: Time) -> Option<(Duration, bool)> {
+    let negative = time.as_seconds() < 0.0;
+    let magnitude = if negative { time * -1.0 } else { time };
+    Duration::try_from(magnitude).ok().map(|duration| (duration, negative))
+}
+
+/// Adds a time to an instant, or returns `None` if the time is not finite or the result is out of the range of an `Instant`.
+///
+/// `Instant` already has a `checked_add` method for a `Duration`, so this is a function rather than a method.
+pub fn checked_add(instant: Instant, time: Time) -> Option<Instant> {
+    match signed_duration(time)? {
+        (duration, false) => instant.checked_add(duration),
+        (duration, true) => instant.checked_sub(duration),
+    }
+}
+
+/// Subtracts a time from an instant, or returns `None` if the time is not finite or the result is out of the range of an `Instant`.
+pub fn checked_sub(instant: Instant, time: Time) -> Option<Instant> {
+    match signed_duration(time)? {
+        (duration, false) => instant.checked_sub(duration),
+        (duration, true) => instant.checked_add(duration),
+    }
+}
+
+impl std::ops::Add<Time> for Instant {
+    type Output = Instant;
+
+    /// # Panics
+    /// Panics if the time is not finite, or if the result is out of the range of an `Instant`. See `checked_add` for a version without panics.
+    fn add(self, time: Time) -> Instant {
+        checked_add(self, time).expect("overflow when adding time to instant")
+    }
+}
+
+impl std::ops::Sub<Time> for Instant {
+    type Output = Instant;
+
+    /// # Panics
+    /// Panics if the time is not finite, or if the result is out of the range of an `Instant`. See `checked_sub` for a version without panics.
+    fn sub(self, time: Time) -> Instant {
+        checked_sub(self, time).expect("overflow when subtracting time from instant")
+    }
+}
+
+/// An absolute point in time, stored in seconds since the Unix epoch, 1970-01-01 00:00:00 UTC, ignoring leap seconds.
+///
+/// The differences between timestamps are a `Time`. Stored as an `f64`, a present day timestamp has a resolution of about a microsecond.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Timestamp(pub f64);
+
+impl Timestamp {
+    /// The Unix epoch, 1970-01-01 00:00:00 UTC.
+    pub const UNIX_EPOCH: Timestamp = Timestamp(0.0);
+
+    pub fn as_unix_seconds(&self) -> f64 {
+        self.0
+    }
+
+    pub fn from_unix_seconds(value: f64) -> Self {
+        Timestamp(value)
+    }
+
+    /// The current time from the system clock.
+    pub fn now() -> Self {
+        Self::from(SystemTime::now())
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => Timestamp(since.as_secs_f64()),
+            Err(before) => Timestamp(-before.duration().as_secs_f64()),
+        }
+    }
+}
+
+impl TryFrom<Timestamp> for SystemTime {
+    type Error = TimeConversionError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, TimeConversionError> {
+        let since_epoch = Time::from_seconds(timestamp.0);
+        let (duration, negative) = match Duration::try_from(since_epoch) {
+            Ok(duration) => (duration, false),
+            Err(TimeConversionError::Negative) => (Duration::try_from(since_epoch * -1.0)?, true),
+            Err(error) => return Err(error),
+        };
+        let time = if negative { UNIX_EPOCH.checked_sub(duration) } else { UNIX_EPOCH.checked_add(duration) };
+        time.ok_or(TimeConversionError::Overflow)
+    }
+}
+
+impl std::ops::Sub for Timestamp {
+    type Output = Time;
+
+    fn sub(self, other: Self) -> Time {
+        Time::from_seconds(self.0 - other.0)
+    }
+}
+
+impl std::ops::Add<Time> for Timestamp {
+    type Output = Self;
+
+    fn add(self, time: Time) -> Self {
+        Timestamp(self.0 + time.as_seconds())
+    }
+}
+
+impl std::ops::Sub<Time> for Timestamp {
+    type Output = Self;
+
+    fn sub(self, time: Time) -> Self {
+        Timestamp(self.0 - time.as_seconds())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::TimeDelta> for Time {
+    fn from(delta: chrono::TimeDelta) -> Self {
+        Time::from_seconds(delta.num_seconds() as f64 + delta.subsec_nanos() as f64 * 1e-9)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Time> for chrono::TimeDelta {
+    type Error = TimeConversionError;
+
+    /// Unlike a `Duration`, a `TimeDelta` can be negative.
+    fn try_from(time: Time) -> Result<Self, TimeConversionError> {
+        let (seconds, nanoseconds) = split_seconds(time.as_seconds())?;
+        chrono::TimeDelta::new(seconds, nanoseconds).ok_or(TimeConversionError::Overflow)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(time: chrono::DateTime<chrono::Utc>) -> Self {
+        Timestamp(time.timestamp() as f64 + time.timestamp_subsec_nanos() as f64 * 1e-9)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = TimeConversionError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, TimeConversionError> {
+        let (seconds, nanoseconds) = split_seconds(timestamp.0)?;
+        chrono::DateTime::from_timestamp(seconds, nanoseconds).ok_or(TimeConversionError::Overflow)
+    }
+}
+
+/// Splits a number of seconds into whole seconds and nanoseconds, rounded to the nearest nanosecond.
+#[cfg(feature = "chrono")]
+fn split_seconds(value: f64) -> Result<(i64, u32), TimeConversionError> {
+    if !value.is_finite() {
+        return Err(TimeConversionError::NotFinite);
+    }
+    let seconds = value.floor();
+    if seconds < i64::MIN as f64 || seconds >= i64::MAX as f64 {
+        return Err(TimeConversionError::Overflow);
+    }
+    let nanoseconds = ((value - seconds) * 1e9).round() as u32;
+    if nanoseconds >= 1_000_000_000 {
+        return Ok((seconds as i64 + 1, 0));
+    }
+    Ok((seconds as i64, nanoseconds))
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp {
+    /// Adds a number of calendar months in UTC, keeping the day of the month and time of day, or returns `None` if the result is out of range.
+    ///
+    /// If the day doesn't exist in the resulting month, the last day of that month is used instead, so 31 January plus one month is the end of February.
+    ///
+    /// ```rust
+    /// use rusty_units::time::Timestamp;
+    ///
+    /// // 2023-01-31 00:00:00 UTC plus a calendar month is 2023-02-28 00:00:00 UTC.
+    /// let end_of_january = Timestamp::from_unix_seconds(1_675_123_200.0);
+    /// let end_of_february = end_of_january.checked_add_months(1).unwrap();
+    /// assert_eq!(end_of_february, Timestamp::from_unix_seconds(1_677_542_400.0));
+    /// assert_eq!((end_of_february - end_of_january).as_days(), 28.0);
+    /// ```
+    pub fn checked_add_months(&self, months: i32) -> Option<Self> {
+        let time = chrono::DateTime::<chrono::Utc>::try_from(*self).ok()?;
+        let shifted = if months >= 0 {
+            time.checked_add_months(chrono::Months::new(months.unsigned_abs()))
+        } else {
+            time.checked_sub_months(chrono::Months::new(months.unsigned_abs()))
+        };
+        shifted.map(Self::from)
+    }
+
+    /// Adds a number of calendar years in UTC, or returns `None` if the result is out of range. 29 February plus one year is 28 February.
+    pub fn checked_add_years(&self, years: i32) -> Option<Self> {
+        self.checked_add_months(years.checked_mul(12)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_conversion() {
+        assert_eq!(Time::from(Duration::from_secs(90)).as_minutes(), 1.5);
+        assert_eq!(Time::from(Duration::from_nanos(1)).as_seconds(), 1e-9);
+        assert_eq!(Duration::try_from(Time::from_hours(1.0)), Ok(Duration::from_secs(3600)));
+        assert_eq!(Duration::try_from(Time::from_seconds(0.0)), Ok(Duration::ZERO));
+
+        // Short durations round-trip to the nanosecond, long ones round to the nearest f64.
+        let exact = Duration::new(86_400, 123_456_789);
+        assert_eq!(Duration::try_from(Time::from(exact)), Ok(exact));
+        let long = Duration::new(10_000_000_000, 123_456_789);
+        let rounded = Duration::try_from(Time::from(long)).unwrap();
+        assert_ne!(rounded, long);
+        assert!(rounded.abs_diff(long) < Duration::from_micros(1));
+    }
+
+    #[test]
+    fn test_unrepresentable_durations() {
+        assert_eq!(Duration::try_from(Time::from_seconds(-0.5)), Err(TimeConversionError::Negative));
+        assert_eq!(Duration::try_from(Time::from_seconds(f64::NAN)), Err(TimeConversionError::NotFinite));
+        assert_eq!(Duration::try_from(Time::from_seconds(f64::INFINITY)), Err(TimeConversionError::NotFinite));
+        assert_eq!(Duration::try_from(Time::from_seconds(1e300)), Err(TimeConversionError::Overflow));
+    }
+
+    #[test]
+    fn test_instant_arithmetic() {
+        let start = Instant::now();
+        let later = start + Time::from_seconds(2.5);
+        assert_eq!(later - start, Duration::from_millis(2500));
+        assert_eq!(later - Time::from_seconds(2.5), start);
+        assert_eq!(later + Time::from_seconds(-2.5), start);
+    }
+
+    #[test]
+    fn test_checked_instant_arithmetic() {
+        let start = Instant::now();
+        let later = start + Time::from_seconds(2.5);
+        assert_eq!(checked_add(start, Time::from_seconds(2.5)), Some(later));
+        assert_eq!(checked_sub(later, Time::from_seconds(2.5)), Some(start));
+        assert_eq!(checked_sub(start, Time::from_seconds(-2.5)), Some(later));
+        assert_eq!(checked_add(start, Time::from_seconds(f64::NAN)), None);
+        assert_eq!(checked_sub(start, Time::from_seconds(f64::NEG_INFINITY)), None);
+        assert_eq!(checked_add(start, Time::from_seconds(1e300)), None);
+        assert_eq!(checked_add(start, Time::from_seconds(1e19)), None);
+    }
+
+    #[test]
+    fn test_timestamp_arithmetic() {
+        let start = Timestamp::from_unix_seconds(1_700_000_000.0);
+        let end = start + Time::from_days(1.0);
+        assert_eq!((end - start).as_hours(), 24.0);
+        assert_eq!(end - Time::from_days(1.0), start);
+        assert!(start < end);
+    }
+
+    #[test]
+    fn test_system_time_conversion() {
+        let time = UNIX_EPOCH + Duration::from_secs(86400);
+        assert_eq!(Timestamp::from(time), Timestamp::from_unix_seconds(86400.0));
+        assert_eq!(SystemTime::try_from(Timestamp::from_unix_seconds(86400.0)), Ok(time));
+
+        let before_epoch = UNIX_EPOCH - Duration::from_secs(60);
+        assert_eq!(Timestamp::from(before_epoch), Timestamp::from_unix_seconds(-60.0));
+        assert_eq!(SystemTime::try_from(Timestamp::from_unix_seconds(-60.0)), Ok(before_epoch));
+        assert_eq!(SystemTime::try_from(Timestamp::from_unix_seconds(f64::NAN)), Err(TimeConversionError::NotFinite));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversion() {
+        let time = chrono::DateTime::from_timestamp(1_700_000_000, 500_000_000).unwrap();
+        assert_eq!(Timestamp::from(time), Timestamp::from_unix_seconds(1_700_000_000.5));
+        assert_eq!(chrono::DateTime::try_from(Timestamp::from_unix_seconds(1_700_000_000.5)), Ok(time));
+
+        assert_eq!(Time::from(chrono::TimeDelta::milliseconds(-1500)).as_seconds(), -1.5);
+        assert_eq!(chrono::TimeDelta::try_from(Time::from_seconds(-1.5)), Ok(chrono::TimeDelta::milliseconds(-1500)));
+        assert_eq!(chrono::TimeDelta::try_from(Time::from_seconds(f64::NAN)), Err(TimeConversionError::NotFinite));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_calendar_arithmetic() {
+        // 2024-02-29 00:00:00 UTC, a leap day.
+        let leap_day = Timestamp::from_unix_seconds(1_709_164_800.0);
+        let next_year = leap_day.checked_add_years(1).unwrap();
+        assert_eq!(next_year, Timestamp::from_unix_seconds(1_740_700_800.0));
+        assert_eq!((next_year - leap_day).as_days(), 365.0);
+        assert_eq!(leap_day.checked_add_months(-1), Some(Timestamp::from_unix_seconds(1_706_486_400.0)));
+        assert_eq!(leap_day.checked_add_years(i32::MAX), None);
+    }
+}
@@ -17,9 +17,17 @@ This module defines various units of measurement and their conversions. It uses
 - minutes
 - hours
 - days
+- weeks
 - years
+- months
+- julian_years
+- gregorian_years
+- tropical_years
 - fortnights
 
+`years` is a common year of 365 days, and `months` is an average month, a twelfth of a Gregorian year. Every unit of `Time` is a fixed length,
+see the `time` module for arithmetic with calendar months and years.
+
 ### LinearVelocity
 - meters_per_second
 - feet_per_second
@@ -51,11 +59,13 @@ This module defines various units of measurement and their conversions. It uses
 - ounces
 - stones
 - tons
+- slugs
 
 ### Force
 - newtons
 - pounds_force
 - dynes
+- kilograms_force
 
 ### Torque
 - newton_meters
@@ -66,6 +76,13 @@ This module defines various units of measurement and their conversions. It uses
 - kelvin
 - celsius
 - fahrenheit
+- rankine
+
+### TemperatureDelta
+- kelvin
+- celsius
+- fahrenheit
+- rankine
 
 ### Energy
 - joules
@@ -86,6 +103,7 @@ This module defines various units of measurement and their conversions. It uses
 - bar
 - psi
 - atmospheres
+- pounds_force_per_square_foot
 
 ### ElectricPotential
 - volts
@@ -111,175 +129,1168 @@ This module defines various units of measurement and their conversions. It uses
 - miles_per_hour_squared
 - kilometers_per_hour_squared
 
+### Area
+- square_meters
+- square_feet
+- square_inches
+- acres
+- hectares
+
+### Volume
+- cubic_meters
+- liters
+- us_gallons
+- imperial_gallons
+- cubic_inches
+- fluid_ounces (US)
+
+### Frequency
+- hertz
+- kilohertz
+- megahertz
+- per_minute
+
+### Resistance
+- ohms
+- kiloohms
+- megaohms
+
+### Conductance
+- siemens
+- millisiemens
+- microsiemens
+
+### Charge
+- coulombs
+- milliampere_hours
+- ampere_hours
+
+### Capacitance
+- farads
+- microfarads
+- nanofarads
+- picofarads
+
+### Inductance
+- henries
+- millihenries
+- microhenries
+
+### MagneticFlux
+- webers
+- milliwebers
+- maxwells
+
+### MagneticFluxDensity
+- teslas
+- milliteslas
+- gauss
+
+### TorqueConstant
+- newton_meters_per_ampere
+- pound_feet_per_ampere
+- ounce_inches_per_ampere
+
+### VelocityConstant
+- radians_per_second_per_volt
+- rotations_per_minute_per_volt
+
+### LinearMomentum
+- kilogram_meters_per_second
+- pound_feet_per_second
+
+### Impulse
+- newton_seconds
+- pound_force_seconds
+
+### Jerk
+- meters_per_second_cubed
+- feet_per_second_cubed
+- inches_per_second_cubed
+
+### AngularJerk
+- radians_per_second_cubed
+- rotations_per_second_cubed
+- degrees_per_second_cubed
+
+### MomentOfInertia
+- kilogram_square_meters
+- pound_square_feet
+- pound_square_inches
+
+### AngularMomentum
+- kilogram_square_meters_per_second
+- pound_square_feet_per_second
+
+### Density
+- kilograms_per_cubic_meter
+- grams_per_cubic_centimeter
+- pounds_per_cubic_foot
+
+### VolumetricFlowRate
+- cubic_meters_per_second
+- liters_per_minute
+- cubic_feet_per_minute
+- us_gallons_per_minute
+- liters_per_second
+- imperial_gallons_per_minute
+
+### MassFlowRate
+- kilograms_per_second
+- grams_per_second
+- kilograms_per_hour
+- pounds_per_minute
+- pounds_per_hour
+- kilograms_per_minute
+
+### DynamicViscosity
+- pascal_seconds
+- poise
+- centipoise
+
+### KinematicViscosity
+- square_meters_per_second
+- stokes
+- centistokes
+
+### HeatCapacity
+- joules_per_kelvin
+- kilojoules_per_kelvin
+
+### SpecificHeatCapacity
+- joules_per_kilogram_kelvin
+- kilojoules_per_kilogram_kelvin
+- btu_per_pound_fahrenheit
+
+### ThermalConductivity
+- watts_per_meter_kelvin
+- btu_per_hour_foot_fahrenheit
+
+### ThermalResistance
+- kelvin_per_watt
+
+### Entropy
+- joules_per_kelvin
+- kilojoules_per_kelvin
+
+### SolidAngle
+- steradians
+- square_degrees
+- spats
+
+### LuminousIntensity
+- candelas
+
+### LuminousFlux
+- lumens
+
+### Illuminance
+- lux
+- foot_candles
+
+### Luminance
+- nits
+- foot_lamberts
+
+### RadiantIntensity
+- watts_per_steradian
+
+### Irradiance
+- watts_per_square_meter
+- milliwatts_per_square_centimeter
+
+### AmountOfSubstance
+- moles
+- kilomoles
+- millimoles
+- micromoles
+
+### MolarMass
+- kilograms_per_mole
+- grams_per_mole
+
+### ReciprocalAmount
+- per_mole
+- per_millimole
+
+### Molarity
+- moles_per_cubic_meter
+- moles_per_liter
+- millimoles_per_liter
+
+### CatalyticActivity
+- katals
+- enzyme_units
+
+### Information
+- bits
+- kilobits
+- megabits
+- gigabits
+- kibibits
+- mebibits
+- bytes
+- kilobytes
+- megabytes
+- gigabytes
+- kibibytes
+- mebibytes
+- gibibytes
+
+### DataRate
+- bits_per_second
+- kilobits_per_second
+- megabits_per_second
+- gigabits_per_second
+- bytes_per_second
+- kilobytes_per_second
+- megabytes_per_second
+- kibibytes_per_second
+- mebibytes_per_second
+
+### Radioactivity
+- becquerels
+- kilobecquerels
+- curies
+- millicuries
+- microcuries
+
+### AbsorbedDose
+- grays
+- milligrays
+- rads
+
+### EquivalentDose
+- sieverts
+- millisieverts
+- microsieverts
+- rems
+- millirems
+
+### AbsorbedDoseRate
+- grays_per_second
+- grays_per_hour
+- milligrays_per_hour
+
+### DoseRate
+- sieverts_per_second
+- sieverts_per_hour
+- microsieverts_per_hour
+- millirems_per_hour
+
+### SpecificEnergy
+- joules_per_kilogram
+- kilojoules_per_kilogram
+- megajoules_per_kilogram
+- btu_per_pound
+
+`AbsorbedDose` and `EquivalentDose` share the same SI dimensions, J/kg, but are kept as separate types, as are their rates,
+so a dose in grays can only become a dose in sieverts through `AbsorbedDose::to_equivalent_dose` with an explicit radiation weighting factor.
+
+## Kinds
+Every dimension apart from the absolute `Temperature` is tagged with its SI dimension using `create_kind!`. Some dimensions share an SI dimension, like `Torque` and `Energy`,
+`Frequency` and `AngularVelocity`, or `AbsorbedDose`, `EquivalentDose`, and `SpecificEnergy`, but they are different kinds of quantity, and don't mix,
+even as a dynamically dimensioned `Quantity`.
+
 ## Unit Operations
+Each relationship also provides all of its rearrangements, so `Distance / Time => LinearVelocity` also gives `LinearVelocity * Time => Distance`,
+`Time * LinearVelocity => Distance`, and `Distance / LinearVelocity => Time`, and `Distance ^ 2 => Area` gives `Distance * Distance => Area` and `Area / Distance => Distance`.
+Squares and cubes also provide `squared`, `sqrt`, `cubed`, `cbrt`, and the `Powi` trait, so `Distance::squared` returns an `Area`, `Area::sqrt` returns
+a `Distance`, and `Powi::<3>::powi(&distance)` returns a `Volume`. Every dimension implements the `Hypot` trait, for the magnitude of two components of
+the same dimension.
+
 - Distance / Time => LinearVelocity
 - Angle / Time => AngularVelocity
 - Force * Distance => Torque
 - Energy / Time => Power
 - Power / ElectricPotential => Current
-- Power / Current => ElectricPotential
-- ElectricPotential * Current => Power
 - LinearVelocity / Time => LinearAcceleration
 - AngularVelocity / Time => AngularAcceleration
+- Distance ^ 2 => Area
+- Area * Distance => Volume
+- Distance ^ 3 => Volume
+- Force / Area => Pressure
+- f64 / Time => Frequency
+- Angle * Frequency => AngularVelocity
+- ElectricPotential / Current => Resistance
+- f64 / Resistance => Conductance
+- Current * Time => Charge
+- Charge * ElectricPotential => Energy
+- Resistance * Capacitance => Time
+- ElectricPotential * Time => MagneticFlux
+- MagneticFlux / Area => MagneticFluxDensity
+- Torque / Current => TorqueConstant
+- AngularVelocity / ElectricPotential => VelocityConstant
+- Mass * LinearAcceleration => Force
+- Force * Time => Impulse
+- Mass * LinearVelocity => LinearMomentum
+- LinearAcceleration / Time => Jerk
+- AngularAcceleration / Time => AngularJerk
+- Mass * Area => MomentOfInertia
+- MomentOfInertia * AngularAcceleration => Torque
+- MomentOfInertia * AngularVelocity => AngularMomentum
+- Torque * Angle => Energy
+- Mass / Volume => Density
+- Volume / Time => VolumetricFlowRate
+- Mass / Time => MassFlowRate
+- Density * VolumetricFlowRate => MassFlowRate
+- Pressure * VolumetricFlowRate => Power
+- Pressure * Time => DynamicViscosity
+- DynamicViscosity / Density => KinematicViscosity
+- Power * ThermalResistance => TemperatureDelta
+- HeatCapacity * TemperatureDelta => Energy
+- Mass * SpecificHeatCapacity => HeatCapacity
+- Energy / Temperature => Entropy
+- LuminousIntensity * SolidAngle => LuminousFlux
+- LuminousFlux / Area => Illuminance
+- LuminousIntensity / Area => Luminance
+- Power / SolidAngle => RadiantIntensity
+- Power / Area => Irradiance
+- Mass / MolarMass => AmountOfSubstance
+- f64 / AmountOfSubstance => ReciprocalAmount
+- AmountOfSubstance / Volume => Molarity
+- AmountOfSubstance / Time => CatalyticActivity
+- Information / Time => DataRate
+- Radioactivity * Time => f64, in this order only, as the reciprocal of a `Time` is a `Frequency`
+- Energy / Mass => AbsorbedDose
+- AbsorbedDose / Time => AbsorbedDoseRate
+- EquivalentDose / Time => DoseRate
+- LinearVelocity ^ 2 => SpecificEnergy
+- SpecificEnergy * Mass => Energy, Mass * SpecificEnergy => Energy, and Energy / SpecificEnergy => Mass, as `Energy / Mass` is an `AbsorbedDose`
+
+`Temperature` is an absolute temperature, so rather than the usual arithmetic, two temperatures can be subtracted to give a `TemperatureDelta`,
+and a `TemperatureDelta` can be added to or subtracted from a temperature.
+
+`AngularVelocity` can also be converted to and from a `Frequency` of rotations with `AngularVelocity::as_frequency` and `AngularVelocity::from_frequency`.
+
+A solid angle isn't the square of a plane angle, so there is no `Angle ^ 2` relationship, but `SolidAngle::of_cone` gives the solid angle of a cone from its half angle.
+
+A `Mass` can be converted to its weight as a `Force` with `Mass::weight`, and back with `Force::mass_equivalent`, under either standard gravity
+or a local gravity from `LinearAcceleration::local_gravity`.
 
 ## Tests
 The module includes comprehensive tests for each unit type and their conversions, ensuring accuracy within a defined epsilon value.
 */
-use crate::{create_unit_operations, create_unit};
+use std::f64::consts::PI;
+
+use crate::{create_derived_converters, create_kind, create_relationships, create_unit, create_unit_operations};
 
+// Each unit is defined by the number of base units in one of that unit, taken from
+// its exact definition wherever one exists. Ratios keep those definitions exact in `f64`.
 
 create_unit!(
     Distance, 
     meters => 1.0,
-    feet => 3.28084,
-    inches => 39.3701,
-    miles => 0.000621371,
-    kilometers => 0.001,
-    nautical_miles => 0.000539957,
-    furlongs => 0.00497096
+    feet => 3048.0 / 10000.0,
+    inches => 254.0 / 10000.0,
+    miles => 1609344.0 / 1000.0,
+    kilometers => 1000.0,
+    nautical_miles => 1852.0,
+    furlongs => 201168.0 / 1000.0
 );
 
 create_unit!(
     Time,
     seconds => 1.0,
-    minutes => 1.0 / 60.0,
-    hours => 1.0 / 3600.0,
-    days => 1.0 / 86400.0,
-    years => 1.0 / 31536000.0,
-    fortnights => 1.0 / 1209600.0
+    minutes => 60.0,
+    hours => 3600.0,
+    days => 86400.0,
+    weeks => 604800.0,
+    years => 31536000.0,
+    months => 2629746.0,
+    julian_years => 31557600.0,
+    gregorian_years => 31556952.0,
+    tropical_years => 31556925.216,
+    fortnights => 1209600.0
 );
 
 create_unit!(
     LinearVelocity,
     meters_per_second => 1.0,
-    feet_per_second => 3.28084,
-    inches_per_second => 39.3701,
-    miles_per_hour => 2.23694,
-    kilometers_per_hour => 3.6,
-    knots => 1.94384,
-    furlongs_per_fortnight => 6012.87
+    feet_per_second => 3048.0 / 10000.0,
+    inches_per_second => 254.0 / 10000.0,
+    miles_per_hour => 1609344.0 / 3600000.0,
+    kilometers_per_hour => 1000.0 / 3600.0,
+    knots => 1852.0 / 3600.0,
+    furlongs_per_fortnight => 201168.0 / 1209600000.0
 );
 
 create_unit!(
     Angle,
     radians => 1.0,
-    rotations => 0.159155,
-    degrees => 180.0 / std::f64::consts::PI,
-    gradians => 200.0 / std::f64::consts::PI,
-    arcminutes => 60.0 * 180.0 / std::f64::consts::PI
+    rotations => (2.0 * PI),
+    degrees => PI / 180.0,
+    gradians => PI / 200.0,
+    arcminutes => PI / 10800.0
 );
 
 create_unit!(
     AngularVelocity,
     radians_per_second => 1.0,
-    rotations_per_second => 0.159155,
-    rotations_per_minute => 0.159155 * 60.0,
-    degrees_per_second => 180.0 / std::f64::consts::PI,
-    gradians_per_second => 200.0 / std::f64::consts::PI,
-    arcminutes_per_second => 60.0 * 180.0 / std::f64::consts::PI
+    rotations_per_second => (2.0 * PI),
+    rotations_per_minute => (2.0 * PI) / 60.0,
+    degrees_per_second => PI / 180.0,
+    gradians_per_second => PI / 200.0,
+    arcminutes_per_second => PI / 10800.0
 );
 
 create_unit!(
     Mass,
     kilograms => 1.0,
-    grams => 1000.0,
-    pounds => 2.20462,
-    ounces => 35.274,
-    stones => 0.157473,
-    tons => 0.001
+    grams => 1.0 / 1000.0,
+    pounds => 45359237.0 / 100000000.0,
+    ounces => 45359237.0 / 1600000000.0,
+    stones => 635029318.0 / 100000000.0,
+    tons => 1000.0,
+    slugs => 44482216152605.0 / 3048000000000.0
 );
 
+// The gravitational units are standard gravity times a mass, ie 1 kilogram force is 9.80665 newtons. They're written as literals, rather
+// than from `constants::STANDARD_GRAVITY`, so that their exact companions are exact, and the tests check that they agree with the constant.
 create_unit!(
     Force,
     newtons => 1.0,
-    pounds_force => 0.224809,
-    dynes => 100000.0
+    pounds_force => 44482216152605.0 / 10000000000000.0,
+    dynes => 1.0 / 100000.0,
+    kilograms_force => 980665.0 / 100000.0
 );
 
 create_unit!(
     Torque,
     newton_meters => 1.0,
-    pound_feet => 0.737562,
-    pound_inches => 8.85075
+    pound_feet => 3389544870828501.0 / 2500000000000000.0,
+    pound_inches => 1129848290276167.0 / 10000000000000000.0
 );
 
+/// An absolute temperature, stored in kelvin.
+///
+/// Unlike other dimensions, temperature scales are offset from each other, so `Temperature` is not created with `create_unit!`,
+/// and only supports the arithmetic that makes sense for absolute temperatures. Differences between temperatures are a `TemperatureDelta`.
+///
+/// For the same reason, `Temperature` doesn't implement `Dimension` or `Kind`, so it can't be used in the generic wrappers like `Measured`
+/// and `Interval`, or as a `Quantity`, whose arithmetic assumes a linear scale. Use a `TemperatureDelta` from a reference temperature instead.
+///
+/// ```compile_fail
+/// use rusty_units::measured::Measured;
+/// use rusty_units::units::Temperature;
+///
+/// let temperature = Measured::exact(Temperature::from_celsius(20.0));
+/// let _ = temperature + temperature;
+/// ```
+///
+/// ```compile_fail
+/// use rusty_units::interval::Interval;
+/// use rusty_units::units::Temperature;
+///
+/// let _ = Interval::new(Temperature::from_celsius(20.0), Temperature::from_celsius(25.0));
+/// ```
+///
+/// ```compile_fail
+/// use rusty_units::quantity::Quantity;
+/// use rusty_units::units::Temperature;
+///
+/// let _ = Quantity::from(Temperature::from_celsius(20.0));
+/// ```
+///
+/// ```rust
+/// use rusty_units::measured::Measured;
+/// use rusty_units::units::{Temperature, TemperatureDelta};
+///
+/// let reference = Temperature::from_celsius(20.0);
+/// let rise = Measured::new(TemperatureDelta::from_kelvin(5.0), TemperatureDelta::from_kelvin(0.5));
+/// assert_eq!((reference + *rise.value()).as_celsius(), 25.0);
+/// assert_eq!(rise.uncertainty().as_celsius(), 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature(pub f64);
+
+impl Temperature {
+    pub fn as_kelvin(&self) -> f64 {
+        self.0
+    }
+
+    pub fn from_kelvin(value: f64) -> Self {
+        Temperature(value)
+    }
+
+    pub fn as_celsius(&self) -> f64 {
+        self.0 - 273.15
+    }
+
+    pub fn from_celsius(value: f64) -> Self {
+        Temperature(value + 273.15)
+    }
+
+    pub fn as_fahrenheit(&self) -> f64 {
+        self.0 * 9.0 / 5.0 - 459.67
+    }
+
+    pub fn from_fahrenheit(value: f64) -> Self {
+        Temperature((value + 459.67) * 5.0 / 9.0)
+    }
+
+    pub fn as_rankine(&self) -> f64 {
+        self.0 * 9.0 / 5.0
+    }
+
+    pub fn from_rankine(value: f64) -> Self {
+        Temperature(value * 5.0 / 9.0)
+    }
+}
+
+impl std::ops::Sub for Temperature {
+    type Output = TemperatureDelta;
+
+    fn sub(self, other: Self) -> TemperatureDelta {
+        TemperatureDelta(self.0 - other.0)
+    }
+}
+
+impl std::ops::Add<TemperatureDelta> for Temperature {
+    type Output = Self;
+
+    fn add(self, delta: TemperatureDelta) -> Self {
+        Self(self.0 + delta.0)
+    }
+}
+
+impl std::ops::Sub<TemperatureDelta> for Temperature {
+    type Output = Self;
+
+    fn sub(self, delta: TemperatureDelta) -> Self {
+        Self(self.0 - delta.0)
+    }
+}
+
 create_unit!(
-    Temperature,
+    TemperatureDelta,
     kelvin => 1.0,
     celsius => 1.0,
-    fahrenheit => 1.8
+    fahrenheit => 5.0 / 9.0,
+    rankine => 5.0 / 9.0
 );
 
 create_unit!(
     Energy,
     joules => 1.0,
-    kilojoules => 0.001,
-    calories => 0.239006,
-    kilocalories => 0.000239006,
-    watt_hours => 0.000277778,
-    kilowatt_hours => 2.77778e-7
+    kilojoules => 1000.0,
+    calories => 4184.0 / 1000.0,
+    kilocalories => 4184.0,
+    watt_hours => 3600.0,
+    kilowatt_hours => 3600000.0
 );
 
 create_unit!(
     Power,
     watts => 1.0,
-    kilowatts => 0.001,
-    horsepower => 0.00134102
+    kilowatts => 1000.0,
+    horsepower => 37284993579113511.0 / 50000000000000.0
 );
 
 create_unit!(
     Pressure,
     pascals => 1.0,
-    kilopascals => 0.001,
-    bar => 1e-5,
-    psi => 0.000145038,
-    atmospheres => 9.86923e-6
+    kilopascals => 1000.0,
+    bar => 100000.0,
+    psi => 44482216152605.0 / 6451600000.0,
+    atmospheres => 101325.0
 );
 
 create_unit!(
     ElectricPotential,
     volts => 1.0,
-    millivolts => 1000.0,
-    microvolts => 1_000_000.0
+    millivolts => 1.0 / 1000.0,
+    microvolts => 1.0 / 1_000_000.0
 );
 
 create_unit!(
     Current,
     amperes => 1.0,
-    milliamperes => 1000.0,
-    microamperes => 1_000_000.0
+    milliamperes => 1.0 / 1000.0,
+    microamperes => 1.0 / 1_000_000.0
 );
 
 create_unit!(
     AngularAcceleration,
     radians_per_second_squared => 1.0,
-    rotations_per_second_squared => 0.159155,
-    degrees_per_second_squared => 180.0 / std::f64::consts::PI,
-    gradians_per_second_squared => 200.0 / std::f64::consts::PI,
-    arcminutes_per_second_squared => 60.0 * 180.0 / std::f64::consts::PI
+    rotations_per_second_squared => (2.0 * PI),
+    degrees_per_second_squared => PI / 180.0,
+    gradians_per_second_squared => PI / 200.0,
+    arcminutes_per_second_squared => PI / 10800.0
 );
 
 create_unit!(
     LinearAcceleration,
     meters_per_second_squared => 1.0,
-    feet_per_second_squared => 3.28084,
-    inches_per_second_squared => 39.3701,
-    miles_per_hour_squared => 2.23694,
-    kilometers_per_hour_squared => 3.6
+    feet_per_second_squared => 3048.0 / 10000.0,
+    inches_per_second_squared => 254.0 / 10000.0,
+    miles_per_hour_squared => 1609344.0 / 12960000000.0,
+    kilometers_per_hour_squared => 1000.0 / 12960000.0
+);
+
+create_unit!(
+    Area,
+    square_meters => 1.0,
+    square_feet => 9290304.0 / 100000000.0,
+    square_inches => 64516.0 / 100000000.0,
+    acres => 40468564224.0 / 10000000.0,
+    hectares => 10000.0
+);
+
+create_unit!(
+    Volume,
+    cubic_meters => 1.0,
+    liters => 1.0 / 1000.0,
+    us_gallons => 3785411784.0 / 1000000000000.0,
+    imperial_gallons => 454609.0 / 100000000.0,
+    cubic_inches => 16387064.0 / 1000000000000.0,
+    fluid_ounces => 3785411784.0 / 128000000000000.0
+);
+
+create_unit!(
+    Frequency,
+    hertz => 1.0,
+    kilohertz => 1000.0,
+    megahertz => 1_000_000.0,
+    per_minute => 1.0 / 60.0
+);
+
+create_unit!(
+    Resistance,
+    ohms => 1.0,
+    kiloohms => 1000.0,
+    megaohms => 1_000_000.0
+);
+
+create_unit!(
+    Conductance,
+    siemens => 1.0,
+    millisiemens => 1.0 / 1000.0,
+    microsiemens => 1.0 / 1_000_000.0
+);
+
+create_unit!(
+    Charge,
+    coulombs => 1.0,
+    milliampere_hours => 3.6,
+    ampere_hours => 3600.0
+);
+
+create_unit!(
+    Capacitance,
+    farads => 1.0,
+    microfarads => 1.0 / 1_000_000.0,
+    nanofarads => 1.0 / 1_000_000_000.0,
+    picofarads => 1.0 / 1_000_000_000_000.0
+);
+
+create_unit!(
+    Inductance,
+    henries => 1.0,
+    millihenries => 1.0 / 1000.0,
+    microhenries => 1.0 / 1_000_000.0
+);
+
+create_unit!(
+    MagneticFlux,
+    webers => 1.0,
+    milliwebers => 1.0 / 1000.0,
+    maxwells => 1.0 / 100_000_000.0
+);
+
+create_unit!(
+    MagneticFluxDensity,
+    teslas => 1.0,
+    milliteslas => 1.0 / 1000.0,
+    gauss => 1.0 / 10000.0
+);
+
+create_unit!(
+    TorqueConstant,
+    newton_meters_per_ampere => 1.0,
+    pound_feet_per_ampere => 3389544870828501.0 / 2500000000000000.0,
+    ounce_inches_per_ampere => 1129848290276167.0 / 160000000000000000.0
+);
+
+create_unit!(
+    VelocityConstant,
+    radians_per_second_per_volt => 1.0,
+    rotations_per_minute_per_volt => (2.0 * PI) / 60.0
+);
+
+create_unit!(
+    LinearMomentum,
+    kilogram_meters_per_second => 1.0,
+    pound_feet_per_second => 138254954376.0 / 1000000000000.0
+);
+
+create_unit!(
+    Impulse,
+    newton_seconds => 1.0,
+    pound_force_seconds => 44482216152605.0 / 10000000000000.0
+);
+
+create_unit!(
+    Jerk,
+    meters_per_second_cubed => 1.0,
+    feet_per_second_cubed => 3048.0 / 10000.0,
+    inches_per_second_cubed => 254.0 / 10000.0
+);
+
+create_unit!(
+    AngularJerk,
+    radians_per_second_cubed => 1.0,
+    rotations_per_second_cubed => (2.0 * PI),
+    degrees_per_second_cubed => PI / 180.0
+);
+
+create_unit!(
+    MomentOfInertia,
+    kilogram_square_meters => 1.0,
+    pound_square_feet => 421401100938048.0 / 10000000000000000.0,
+    pound_square_inches => 2926396534292.0 / 10000000000000000.0
+);
+
+create_unit!(
+    AngularMomentum,
+    kilogram_square_meters_per_second => 1.0,
+    pound_square_feet_per_second => 421401100938048.0 / 10000000000000000.0
+);
+
+create_unit!(
+    Density,
+    kilograms_per_cubic_meter => 1.0,
+    grams_per_cubic_centimeter => 1000.0,
+    pounds_per_cubic_foot => 453592370000.0 / 28316846592.0
+);
+
+create_unit!(
+    VolumetricFlowRate,
+    cubic_meters_per_second => 1.0,
+    liters_per_minute => 1.0 / 60000.0,
+    cubic_feet_per_minute => 28316846592.0 / 60000000000000.0,
+    us_gallons_per_minute => 3785411784.0 / 60000000000000.0
+);
+
+create_unit!(
+    MassFlowRate,
+    kilograms_per_second => 1.0,
+    grams_per_second => 1.0 / 1000.0,
+    kilograms_per_hour => 1.0 / 3600.0,
+    pounds_per_minute => 45359237.0 / 6000000000.0,
+    pounds_per_hour => 45359237.0 / 360000000000.0
+);
+
+create_unit!(
+    DynamicViscosity,
+    pascal_seconds => 1.0,
+    poise => 1.0 / 10.0,
+    centipoise => 1.0 / 1000.0
+);
+
+create_unit!(
+    KinematicViscosity,
+    square_meters_per_second => 1.0,
+    stokes => 1.0 / 10000.0,
+    centistokes => 1.0 / 1_000_000.0
+);
+
+create_unit!(
+    HeatCapacity,
+    joules_per_kelvin => 1.0,
+    kilojoules_per_kelvin => 1000.0
+);
+
+create_unit!(
+    SpecificHeatCapacity,
+    joules_per_kilogram_kelvin => 1.0,
+    kilojoules_per_kilogram_kelvin => 1000.0,
+    btu_per_pound_fahrenheit => 4186.8
+);
+
+create_unit!(
+    ThermalConductivity,
+    watts_per_meter_kelvin => 1.0,
+    btu_per_hour_foot_fahrenheit => 949550267358.0 / 548640000000.0
+);
+
+create_unit!(
+    ThermalResistance,
+    kelvin_per_watt => 1.0
+);
+
+create_unit!(
+    Entropy,
+    joules_per_kelvin => 1.0,
+    kilojoules_per_kelvin => 1000.0
+);
+
+create_unit!(
+    SolidAngle,
+    steradians => 1.0,
+    square_degrees => (PI * PI) / 32400.0,
+    spats => (4.0 * PI)
+);
+
+create_unit!(
+    LuminousIntensity,
+    candelas => 1.0
+);
+
+create_unit!(
+    LuminousFlux,
+    lumens => 1.0
+);
+
+create_unit!(
+    Illuminance,
+    lux => 1.0,
+    foot_candles => 100000000.0 / 9290304.0
+);
+
+create_unit!(
+    Luminance,
+    nits => 1.0,
+    foot_lamberts => 100000000.0 / (9290304.0 * PI)
+);
+
+create_unit!(
+    RadiantIntensity,
+    watts_per_steradian => 1.0
+);
+
+create_unit!(
+    Irradiance,
+    watts_per_square_meter => 1.0,
+    milliwatts_per_square_centimeter => 10.0
+);
+
+create_unit!(
+    AmountOfSubstance,
+    moles => 1.0,
+    kilomoles => 1000.0,
+    millimoles => 1.0 / 1000.0,
+    micromoles => 1.0 / 1_000_000.0
+);
+
+create_unit!(
+    MolarMass,
+    kilograms_per_mole => 1.0,
+    grams_per_mole => 1.0 / 1000.0
+);
+
+create_unit!(
+    ReciprocalAmount,
+    per_mole => 1.0,
+    per_millimole => 1000.0
+);
+
+create_unit!(
+    Molarity,
+    moles_per_cubic_meter => 1.0,
+    moles_per_liter => 1000.0,
+    millimoles_per_liter => 1.0
+);
+
+create_unit!(
+    CatalyticActivity,
+    katals => 1.0,
+    enzyme_units => 1.0 / 60_000_000.0
+);
+
+// Information is based on bits, so every unit is an exact integer multiple of the base unit.
+create_unit!(
+    Information,
+    bits => 1.0,
+    kilobits => 1000.0,
+    megabits => 1_000_000.0,
+    gigabits => 1_000_000_000.0,
+    kibibits => 1024.0,
+    mebibits => 1_048_576.0,
+    bytes => 8.0,
+    kilobytes => 8000.0,
+    megabytes => 8_000_000.0,
+    gigabytes => 8_000_000_000.0,
+    kibibytes => 8192.0,
+    mebibytes => 8_388_608.0,
+    gibibytes => 8_589_934_592.0
 );
 
+create_unit!(
+    DataRate,
+    bits_per_second => 1.0,
+    kilobits_per_second => 1000.0,
+    megabits_per_second => 1_000_000.0,
+    gigabits_per_second => 1_000_000_000.0,
+    bytes_per_second => 8.0,
+    kilobytes_per_second => 8000.0,
+    megabytes_per_second => 8_000_000.0,
+    kibibytes_per_second => 8192.0,
+    mebibytes_per_second => 8_388_608.0
+);
+
+create_unit!(
+    Radioactivity,
+    becquerels => 1.0,
+    kilobecquerels => 1000.0,
+    curies => 37_000_000_000.0,
+    millicuries => 37_000_000.0,
+    microcuries => 37_000.0
+);
+
+create_unit!(
+    AbsorbedDose,
+    grays => 1.0,
+    milligrays => 1.0 / 1000.0,
+    rads => 1.0 / 100.0
+);
+
+create_unit!(
+    EquivalentDose,
+    sieverts => 1.0,
+    millisieverts => 1.0 / 1000.0,
+    microsieverts => 1.0 / 1_000_000.0,
+    rems => 1.0 / 100.0,
+    millirems => 1.0 / 100_000.0
+);
+
+create_unit!(
+    AbsorbedDoseRate,
+    grays_per_second => 1.0,
+    grays_per_hour => 1.0 / 3600.0,
+    milligrays_per_hour => 1.0 / 3_600_000.0
+);
+
+create_unit!(
+    DoseRate,
+    sieverts_per_second => 1.0,
+    sieverts_per_hour => 1.0 / 3600.0,
+    microsieverts_per_hour => 1.0 / 3_600_000_000.0,
+    millirems_per_hour => 1.0 / 360_000_000.0
+);
+
+create_unit!(
+    SpecificEnergy,
+    joules_per_kilogram => 1.0,
+    kilojoules_per_kilogram => 1000.0,
+    megajoules_per_kilogram => 1_000_000.0,
+    btu_per_pound => 2326.0
+);
+
+
+// Every linear dimension is tagged with its SI dimension, so that kinds sharing a dimension, like Torque and Energy, stay distinct.
+create_kind!(Distance, length: 1);
+create_kind!(Time, time: 1);
+create_kind!(LinearVelocity, length: 1, time: -1);
+create_kind!(Angle);
+create_kind!(AngularVelocity, time: -1);
+create_kind!(Mass, mass: 1);
+create_kind!(Force, length: 1, mass: 1, time: -2);
+create_kind!(Torque, length: 2, mass: 1, time: -2);
+create_kind!(TemperatureDelta, temperature: 1);
+create_kind!(Energy, length: 2, mass: 1, time: -2);
+create_kind!(Power, length: 2, mass: 1, time: -3);
+create_kind!(Pressure, length: -1, mass: 1, time: -2);
+create_kind!(ElectricPotential, length: 2, mass: 1, time: -3, current: -1);
+create_kind!(Current, current: 1);
+create_kind!(AngularAcceleration, time: -2);
+create_kind!(LinearAcceleration, length: 1, time: -2);
+create_kind!(Area, length: 2);
+create_kind!(Volume, length: 3);
+create_kind!(Frequency, time: -1);
+create_kind!(Resistance, length: 2, mass: 1, time: -3, current: -2);
+create_kind!(Conductance, length: -2, mass: -1, time: 3, current: 2);
+create_kind!(Charge, time: 1, current: 1);
+create_kind!(Capacitance, length: -2, mass: -1, time: 4, current: 2);
+create_kind!(Inductance, length: 2, mass: 1, time: -2, current: -2);
+create_kind!(MagneticFlux, length: 2, mass: 1, time: -2, current: -1);
+create_kind!(MagneticFluxDensity, mass: 1, time: -2, current: -1);
+create_kind!(TorqueConstant, length: 2, mass: 1, time: -2, current: -1);
+create_kind!(VelocityConstant, length: -2, mass: -1, time: 2, current: 1);
+create_kind!(LinearMomentum, length: 1, mass: 1, time: -1);
+create_kind!(Impulse, length: 1, mass: 1, time: -1);
+create_kind!(Jerk, length: 1, time: -3);
+create_kind!(AngularJerk, time: -3);
+create_kind!(MomentOfInertia, length: 2, mass: 1);
+create_kind!(AngularMomentum, length: 2, mass: 1, time: -1);
+create_kind!(Density, length: -3, mass: 1);
+create_kind!(VolumetricFlowRate, length: 3, time: -1);
+create_kind!(MassFlowRate, mass: 1, time: -1);
+create_kind!(DynamicViscosity, length: -1, mass: 1, time: -1);
+create_kind!(KinematicViscosity, length: 2, time: -1);
+create_kind!(HeatCapacity, length: 2, mass: 1, time: -2, temperature: -1);
+create_kind!(SpecificHeatCapacity, length: 2, time: -2, temperature: -1);
+create_kind!(ThermalConductivity, length: 1, mass: 1, time: -3, temperature: -1);
+create_kind!(ThermalResistance, length: -2, mass: -1, time: 3, temperature: 1);
+create_kind!(Entropy, length: 2, mass: 1, time: -2, temperature: -1);
+create_kind!(SolidAngle);
+create_kind!(LuminousIntensity, luminous_intensity: 1);
+create_kind!(LuminousFlux, luminous_intensity: 1);
+create_kind!(Illuminance, length: -2, luminous_intensity: 1);
+create_kind!(Luminance, length: -2, luminous_intensity: 1);
+create_kind!(RadiantIntensity, length: 2, mass: 1, time: -3);
+create_kind!(Irradiance, mass: 1, time: -3);
+create_kind!(AmountOfSubstance, amount: 1);
+create_kind!(MolarMass, mass: 1, amount: -1);
+create_kind!(ReciprocalAmount, amount: -1);
+create_kind!(Molarity, length: -3, amount: 1);
+create_kind!(CatalyticActivity, time: -1, amount: 1);
+create_kind!(Information);
+create_kind!(DataRate, time: -1);
+create_kind!(Radioactivity, time: -1);
+create_kind!(AbsorbedDose, length: 2, time: -2);
+create_kind!(EquivalentDose, length: 2, time: -2);
+create_kind!(AbsorbedDoseRate, length: 2, time: -3);
+create_kind!(DoseRate, length: 2, time: -3);
+create_kind!(SpecificEnergy, length: 2, time: -2);
+
+create_relationships! {
+    /// Every multiplication and division between the built-in dimensions, which `Quantity` always uses to find the kind of a product or quotient.
+    pub(crate) const KIND_OPERATIONS;
+    Distance / Time => LinearVelocity,
+    Angle / Time => AngularVelocity,
+    Force * Distance => Torque,
+    Energy / Time => Power,
+    Power / ElectricPotential => Current,
+    LinearVelocity / Time => LinearAcceleration,
+    AngularVelocity / Time => AngularAcceleration,
+    Distance ^ 2 => Area,
+    Area * Distance => Volume,
+    Distance ^ 3 => Volume,
+    Force / Area => Pressure,
+    f64 / Time => Frequency,
+    Angle * Frequency => AngularVelocity,
+    ElectricPotential / Current => Resistance,
+    f64 / Resistance => Conductance,
+    Current * Time => Charge,
+    Charge * ElectricPotential => Energy,
+    Resistance * Capacitance => Time,
+    ElectricPotential * Time => MagneticFlux,
+    MagneticFlux / Area => MagneticFluxDensity,
+    Torque / Current => TorqueConstant,
+    AngularVelocity / ElectricPotential => VelocityConstant,
+    Mass * LinearAcceleration => Force,
+    Force * Time => Impulse,
+    Mass * LinearVelocity => LinearMomentum,
+    LinearAcceleration / Time => Jerk,
+    AngularAcceleration / Time => AngularJerk,
+    Mass * Area => MomentOfInertia,
+    MomentOfInertia * AngularAcceleration => Torque,
+    MomentOfInertia * AngularVelocity => AngularMomentum,
+    Torque * Angle => Energy,
+    Mass / Volume => Density,
+    Volume / Time => VolumetricFlowRate,
+    Mass / Time => MassFlowRate,
+    Density * VolumetricFlowRate => MassFlowRate,
+    Pressure * VolumetricFlowRate => Power,
+    Pressure * Time => DynamicViscosity,
+    DynamicViscosity / Density => KinematicViscosity,
+    Power * ThermalResistance => TemperatureDelta,
+    HeatCapacity * TemperatureDelta => Energy,
+    Mass * SpecificHeatCapacity => HeatCapacity,
+    LuminousIntensity * SolidAngle => LuminousFlux,
+    LuminousFlux / Area => Illuminance,
+    LuminousIntensity / Area => Luminance,
+    Power / SolidAngle => RadiantIntensity,
+    Power / Area => Irradiance,
+    Mass / MolarMass => AmountOfSubstance,
+    f64 / AmountOfSubstance => ReciprocalAmount,
+    AmountOfSubstance / Volume => Molarity,
+    AmountOfSubstance / Time => CatalyticActivity,
+    Information / Time => DataRate,
+    single Radioactivity * Time => f64,
+    Energy / Mass => AbsorbedDose,
+    AbsorbedDose / Time => AbsorbedDoseRate,
+    EquivalentDose / Time => DoseRate,
+    LinearVelocity ^ 2 => SpecificEnergy,
+    single SpecificEnergy * Mass => Energy,
+    single Mass * SpecificEnergy => Energy,
+    single Energy / SpecificEnergy => Mass,
+}
+
+// An absolute `Temperature` isn't a kind, so it can't be a `Quantity`, and its relationship isn't recorded with the others.
+create_unit_operations!(Energy / Temperature => Entropy);
+
+// Units that are a pair of units of the operands are derived from them, rather than written out by hand.
+create_derived_converters!(
+    Force / Area => Pressure,
+    pounds_force / square_feet => pounds_force_per_square_foot
+);
+create_derived_converters!(
+    Volume / Time => VolumetricFlowRate,
+    liters / seconds => liters_per_second,
+    imperial_gallons / minutes => imperial_gallons_per_minute
+);
+create_derived_converters!(
+    Mass / Time => MassFlowRate,
+    kilograms / minutes => kilograms_per_minute
+);
+
+impl AngularVelocity {
+    /// Converts a frequency of full rotations into an angular velocity, so 1 Hz is 1 rotation per second.
+    pub fn from_frequency(frequency: Frequency) -> Self {
+        Self::from_rotations_per_second(frequency.as_hertz())
+    }
+
+    /// Converts an angular velocity into the frequency of full rotations it makes.
+    pub fn as_frequency(&self) -> Frequency {
+        Frequency::from_hertz(self.as_rotations_per_second())
+    }
+}
+
+
+impl SolidAngle {
+    /// The solid angle subtended by a cone with the apex angle `2 * half_angle`, which is 2π(1 - cos θ) steradians.
+    ///
+    /// A solid angle isn't the square of a plane angle, so this is the way to get one from an `Angle`. A half angle of 180 degrees is the whole sphere.
+    pub fn of_cone(half_angle: Angle) -> Self {
+        Self::from_steradians(2.0 * PI * (1.0 - half_angle.as_radians().cos()))
+    }
+}
+
+impl AbsorbedDose {
+    /// Converts an absorbed dose into an equivalent dose using the radiation weighting factor of the radiation absorbed,
+    /// ie 1 for photons and electrons, or 20 for alpha particles.
+    pub fn to_equivalent_dose(&self, weighting_factor: f64) -> EquivalentDose {
+        EquivalentDose(self.0 * weighting_factor)
+    }
+}
+
+impl Mass {
+    /// The weight of the mass under the acceleration of gravity `gravity`.
+    pub fn weight(&self, gravity: LinearAcceleration) -> Force {
+        *self * gravity
+    }
+
+    /// The weight of the mass under standard gravity, so a mass of 1 pound weighs 1 pound force.
+    pub fn standard_weight(&self) -> Force {
+        self.weight(crate::constants::STANDARD_GRAVITY)
+    }
+}
 
-create_unit_operations!(Distance / Time => LinearVelocity);
-create_unit_operations!(Angle / Time => AngularVelocity);
-create_unit_operations!(Force * Distance => Torque);
-create_unit_operations!(Energy / Time => Power);
-create_unit_operations!(Power / ElectricPotential => Current);
-create_unit_operations!(Power / Current => ElectricPotential);
-create_unit_operations!(ElectricPotential * Current => Power);
-create_unit_operations!(LinearVelocity / Time => LinearAcceleration);
-create_unit_operations!(AngularVelocity / Time => AngularAcceleration);
+impl Force {
+    /// The mass whose weight under the acceleration of gravity `gravity` is this force.
+    pub fn mass_equivalent(&self, gravity: LinearAcceleration) -> Mass {
+        *self / gravity
+    }
 
+    /// The mass whose weight under standard gravity is this force, so a force of 1 kilogram force is a mass of 1 kilogram.
+    pub fn standard_mass_equivalent(&self) -> Mass {
+        self.mass_equivalent(crate::constants::STANDARD_GRAVITY)
+    }
+}
 
+impl LinearAcceleration {
+    /// The acceleration of gravity at a geodetic `latitude` and an `altitude` above the WGS 84 ellipsoid.
+    ///
+    /// This uses the WGS 84 normal gravity formula with a free-air correction for altitude, which ignores local variations like terrain,
+    /// and is accurate to within about 0.01% near the surface.
+    pub fn local_gravity(latitude: Angle, altitude: Distance) -> Self {
+        let sin_squared = latitude.as_radians().sin().powi(2);
+        let sea_level = 9.7803253359 * (1.0 + 0.00193185265241 * sin_squared) / (1.0 - 0.00669437999013 * sin_squared).sqrt();
+        Self::from_meters_per_second_squared(sea_level - 3.086e-6 * altitude.as_meters())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -290,12 +1301,12 @@ mod tests {
     #[test]
     fn test_distance_conversion() {
         let meters = Distance::from_meters(1.0);
-        assert!((meters.as_feet() - 3.28084).abs() < EPSILON);
-        assert!((meters.as_inches() - 39.3701).abs() < EPSILON);
-        assert!((meters.as_miles() - 0.000621371).abs() < EPSILON);
+        assert!((meters.as_feet() - 1.0 / 0.3048).abs() < EPSILON);
+        assert!((meters.as_inches() - 1.0 / 0.0254).abs() < EPSILON);
+        assert!((meters.as_miles() - 1.0 / 1609.344).abs() < EPSILON);
         assert!((meters.as_kilometers() - 0.001).abs() < EPSILON);
-        assert!((meters.as_nautical_miles() - 0.000539957).abs() < EPSILON);
-        assert!((meters.as_furlongs() - 0.00497096).abs() < EPSILON);
+        assert!((meters.as_nautical_miles() - 1.0 / 1852.0).abs() < EPSILON);
+        assert!((meters.as_furlongs() - 1.0 / 201.168).abs() < EPSILON);
     }
 
     #[test]
@@ -311,18 +1322,18 @@ mod tests {
     #[test]
     fn test_linear_velocity_conversion() {
         let mps = LinearVelocity::from_meters_per_second(1.0);
-        assert!((mps.as_feet_per_second() - 3.28084).abs() < EPSILON);
-        assert!((mps.as_inches_per_second() - 39.3701).abs() < EPSILON);
-        assert!((mps.as_miles_per_hour() - 2.23694).abs() < EPSILON);
+        assert!((mps.as_feet_per_second() - 1.0 / 0.3048).abs() < EPSILON);
+        assert!((mps.as_inches_per_second() - 1.0 / 0.0254).abs() < EPSILON);
+        assert!((mps.as_miles_per_hour() - 3600.0 / 1609.344).abs() < EPSILON);
         assert!((mps.as_kilometers_per_hour() - 3.6).abs() < EPSILON);
-        assert!((mps.as_knots() - 1.94384).abs() < EPSILON);
-        assert!((mps.as_furlongs_per_fortnight() - 6012.87).abs() < EPSILON);
+        assert!((mps.as_knots() - 3600.0 / 1852.0).abs() < EPSILON);
+        assert!((mps.as_furlongs_per_fortnight() - 1209600.0 / 201.168).abs() < EPSILON);
     }
 
     #[test]
     fn test_angle_conversion() {
         let radians = Angle::from_radians(1.0);
-        assert!((radians.as_rotations() - 0.159155).abs() < EPSILON);
+        assert!((radians.as_rotations() - 1.0 / (2.0 * std::f64::consts::PI)).abs() < EPSILON);
         assert!((radians.as_degrees() - (180.0 / std::f64::consts::PI)).abs() < EPSILON);
         assert!((radians.as_gradians() - (200.0 / std::f64::consts::PI)).abs() < EPSILON);
         assert!((radians.as_arcminutes() - (60.0 * 180.0 / std::f64::consts::PI)).abs() < EPSILON);
@@ -331,8 +1342,8 @@ mod tests {
     #[test]
     fn test_angular_velocity_conversion() {
         let rps = AngularVelocity::from_radians_per_second(1.0);
-        assert!((rps.as_rotations_per_second() - 0.159155).abs() < EPSILON);
-        assert!((rps.as_rotations_per_minute() - (0.159155 * 60.0)).abs() < EPSILON);
+        assert!((rps.as_rotations_per_second() - 1.0 / (2.0 * std::f64::consts::PI)).abs() < EPSILON);
+        assert!((rps.as_rotations_per_minute() - 60.0 / (2.0 * std::f64::consts::PI)).abs() < EPSILON);
         assert!((rps.as_degrees_per_second() - (180.0 / std::f64::consts::PI)).abs() < EPSILON);
         assert!((rps.as_gradians_per_second() - (200.0 / std::f64::consts::PI)).abs() < EPSILON);
         assert!((rps.as_arcminutes_per_second() - (60.0 * 180.0 / std::f64::consts::PI)).abs() < EPSILON);
@@ -342,48 +1353,70 @@ mod tests {
     fn test_mass_conversion() {
         let kg = Mass::from_kilograms(1.0);
         assert!((kg.as_grams() - 1000.0).abs() < EPSILON);
-        assert!((kg.as_pounds() - 2.20462).abs() < EPSILON);
-        assert!((kg.as_ounces() - 35.274).abs() < EPSILON);
-        assert!((kg.as_stones() - 0.157473).abs() < EPSILON);
+        assert!((kg.as_pounds() - 1.0 / 0.45359237).abs() < EPSILON);
+        assert!((kg.as_ounces() - 16.0 / 0.45359237).abs() < EPSILON);
+        assert!((kg.as_stones() - 1.0 / 6.35029318).abs() < EPSILON);
         assert!((kg.as_tons() - 0.001).abs() < EPSILON);
     }
 
     #[test]
     fn test_force_conversion() {
         let newtons = Force::from_newtons(1.0);
-        assert!((newtons.as_pounds_force() - 0.224809).abs() < EPSILON);
+        assert!((newtons.as_pounds_force() - 1.0 / 4.4482216152605).abs() < EPSILON);
         assert!((newtons.as_dynes() - 100000.0).abs() < EPSILON);
     }
 
     #[test]
     fn test_torque_conversion() {
         let nm = Torque::from_newton_meters(1.0);
-        assert!((nm.as_pound_feet() - 0.737562).abs() < EPSILON);
-        assert!((nm.as_pound_inches() - 8.85075).abs() < EPSILON);
+        assert!((nm.as_pound_feet() - 1.0 / (4.4482216152605 * 0.3048)).abs() < EPSILON);
+        assert!((nm.as_pound_inches() - 1.0 / (4.4482216152605 * 0.0254)).abs() < EPSILON);
     }
 
     #[test]
     fn test_temperature_conversion() {
-        let kelvin = Temperature::from_kelvin(1.0);
+        let freezing = Temperature::from_celsius(0.0);
+        assert!((freezing.as_kelvin() - 273.15).abs() < EPSILON);
+        assert!((freezing.as_fahrenheit() - 32.0).abs() < EPSILON);
+        assert!((freezing.as_rankine() - 491.67).abs() < EPSILON);
+        assert!((Temperature::from_fahrenheit(212.0).as_celsius() - 100.0).abs() < EPSILON);
+        assert!((Temperature::from_fahrenheit(-40.0).as_celsius() + 40.0).abs() < EPSILON);
+        assert!((Temperature::from_rankine(0.0).as_kelvin()).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_temperature_delta_conversion() {
+        let kelvin = TemperatureDelta::from_kelvin(1.0);
         assert!((kelvin.as_celsius() - 1.0).abs() < EPSILON);
         assert!((kelvin.as_fahrenheit() - 1.8).abs() < EPSILON);
+        assert!((kelvin.as_rankine() - 1.8).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_temperature_arithmetic() {
+        let delta = Temperature::from_celsius(100.0) - Temperature::from_fahrenheit(32.0);
+        assert!((delta.as_fahrenheit() - 180.0).abs() < EPSILON);
+        let warmed = Temperature::from_celsius(20.0) + TemperatureDelta::from_celsius(5.0);
+        assert!((warmed.as_celsius() - 25.0).abs() < EPSILON);
+        let cooled = Temperature::from_celsius(20.0) - TemperatureDelta::from_fahrenheit(9.0);
+        assert!((cooled.as_celsius() - 15.0).abs() < EPSILON);
     }
 
     #[test]
     fn test_energy_conversion() {
         let joules = Energy::from_joules(1.0);
         assert!((joules.as_kilojoules() - 0.001).abs() < EPSILON);
-        assert!((joules.as_calories() - 0.239006).abs() < EPSILON);
-        assert!((joules.as_kilocalories() - 0.000239006).abs() < EPSILON);
-        assert!((joules.as_watt_hours() - 0.000277778).abs() < EPSILON);
-        assert!((joules.as_kilowatt_hours() - 2.77778e-7).abs() < EPSILON);
+        assert!((joules.as_calories() - 1.0 / 4.184).abs() < EPSILON);
+        assert!((joules.as_kilocalories() - 1.0 / 4184.0).abs() < EPSILON);
+        assert!((joules.as_watt_hours() - 1.0 / 3600.0).abs() < EPSILON);
+        assert!((joules.as_kilowatt_hours() - 1.0 / 3.6e6).abs() < EPSILON);
     }
 
     #[test]
     fn test_power_conversion() {
         let watts = Power::from_watts(1.0);
         assert!((watts.as_kilowatts() - 0.001).abs() < EPSILON);
-        assert!((watts.as_horsepower() - 0.00134102).abs() < EPSILON);
+        assert!((watts.as_horsepower() - 1.0 / 745.6998715822702).abs() < EPSILON);
     }
 
     #[test]
@@ -391,8 +1424,8 @@ mod tests {
         let pascals = Pressure::from_pascals(1.0);
         assert!((pascals.as_kilopascals() - 0.001).abs() < EPSILON);
         assert!((pascals.as_bar() - 1e-5).abs() < EPSILON);
-        assert!((pascals.as_psi() - 0.000145038).abs() < EPSILON);
-        assert!((pascals.as_atmospheres() - 9.86923e-6).abs() < EPSILON);
+        assert!((pascals.as_psi() - 0.0254 * 0.0254 / 4.4482216152605).abs() < EPSILON);
+        assert!((pascals.as_atmospheres() - 1.0 / 101325.0).abs() < EPSILON);
     }
 
     #[test]
@@ -410,59 +1443,953 @@ mod tests {
     }
 
     #[test]
-    fn test_linear_velocity_from_distance_and_time() {
-        let distance = Distance::from_meters(100.0);
-        let time = Time::from_seconds(10.0);
-        let velocity = distance / time;
-        assert!((velocity.as_meters_per_second() - 10.0).abs() < EPSILON);
+    fn test_area_conversion() {
+        let square_meters = Area::from_square_meters(1.0);
+        assert!((square_meters.as_square_feet() - 1.0 / (0.3048 * 0.3048)).abs() < EPSILON);
+        assert!((square_meters.as_square_inches() - 1.0 / (0.0254 * 0.0254)).abs() < EPSILON);
+        assert!((square_meters.as_acres() - 1.0 / 4046.8564224).abs() < EPSILON);
+        assert!((square_meters.as_hectares() - 0.0001).abs() < EPSILON);
+        assert_eq!(Area::from_acres(1.0).as_square_feet(), 43560.0);
     }
 
     #[test]
-    fn test_angular_velocity_from_angle_and_time() {
-        let angle = Angle::from_radians(2.0 * std::f64::consts::PI);
-        let time = Time::from_seconds(1.0);
-        let angular_velocity = angle / time;
-        assert!((angular_velocity.as_rotations_per_second() - 1.0).abs() < EPSILON, "{}", angular_velocity.as_rotations_per_second());
+    fn test_volume_conversion() {
+        let cubic_meters = Volume::from_cubic_meters(1.0);
+        assert!((cubic_meters.as_liters() - 1000.0).abs() < EPSILON);
+        assert!((cubic_meters.as_us_gallons() - 1.0 / 0.003785411784).abs() < EPSILON);
+        assert!((cubic_meters.as_imperial_gallons() - 1.0 / 0.00454609).abs() < EPSILON);
+        assert!((cubic_meters.as_cubic_inches() - 1.0 / 0.000016387064).abs() < EPSILON);
+        assert!((cubic_meters.as_fluid_ounces() - 128.0 / 0.003785411784).abs() < EPSILON);
+        assert_eq!(Volume::from_us_gallons(1.0).as_cubic_inches(), 231.0);
+        assert_eq!(Volume::from_us_gallons(1.0).as_fluid_ounces(), 128.0);
     }
 
     #[test]
-    fn test_torque_from_force_and_distance() {
-        let force = Force::from_newtons(10.0);
-        let distance = Distance::from_meters(2.0);
-        let torque = force * distance;
-        assert!((torque.as_newton_meters() - 20.0).abs() < EPSILON);
+    fn test_frequency_conversion() {
+        let hertz = Frequency::from_hertz(1.0);
+        assert!((hertz.as_kilohertz() - 0.001).abs() < EPSILON);
+        assert!((hertz.as_megahertz() - 1e-6).abs() < EPSILON);
+        assert!((hertz.as_per_minute() - 60.0).abs() < EPSILON);
     }
 
     #[test]
-    fn test_power_from_energy_and_time() {
-        let energy = Energy::from_joules(100.0);
-        let time = Time::from_seconds(10.0);
-        let power = energy / time;
-        assert!((power.as_watts() - 10.0).abs() < EPSILON);
+    fn test_resistance_conversion() {
+        let ohms = Resistance::from_ohms(1.0);
+        assert!((ohms.as_kiloohms() - 0.001).abs() < EPSILON);
+        assert!((ohms.as_megaohms() - 1e-6).abs() < EPSILON);
     }
 
     #[test]
-    fn test_current_from_power_and_electric_potential() {
-        let power = Power::from_watts(100.0);
-        let voltage = ElectricPotential::from_volts(10.0);
-        let current = power / voltage;
-        assert!((current.as_amperes() - 10.0).abs() < EPSILON);
+    fn test_conductance_conversion() {
+        let siemens = Conductance::from_siemens(1.0);
+        assert!((siemens.as_millisiemens() - 1000.0).abs() < EPSILON);
+        assert!((siemens.as_microsiemens() - 1_000_000.0).abs() < EPSILON);
     }
 
     #[test]
-    fn test_electric_potential_from_power_and_current() {
-        let power = Power::from_watts(100.0);
-        let current = Current::from_amperes(10.0);
-        let voltage = power / current;
-        assert!((voltage.as_volts() - 10.0).abs() < EPSILON);
+    fn test_charge_conversion() {
+        let coulombs = Charge::from_coulombs(3600.0);
+        assert!((coulombs.as_ampere_hours() - 1.0).abs() < EPSILON);
+        assert!((coulombs.as_milliampere_hours() - 1000.0).abs() < EPSILON);
     }
 
     #[test]
-    fn test_power_from_electric_potential_and_current() {
-        let voltage = ElectricPotential::from_volts(10.0);
-        let current = Current::from_amperes(10.0);
-        let power = voltage * current;
-        assert!((power.as_watts() - 100.0).abs() < EPSILON);
+    fn test_capacitance_conversion() {
+        let farads = Capacitance::from_farads(1e-6);
+        assert!((farads.as_microfarads() - 1.0).abs() < EPSILON);
+        assert!((farads.as_nanofarads() - 1000.0).abs() < EPSILON);
+        assert!((farads.as_picofarads() - 1_000_000.0).abs() < EPSILON);
     }
-}
 
+    #[test]
+    fn test_inductance_conversion() {
+        let henries = Inductance::from_henries(1.0);
+        assert!((henries.as_millihenries() - 1000.0).abs() < EPSILON);
+        assert!((henries.as_microhenries() - 1_000_000.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_magnetic_flux_conversion() {
+        let webers = MagneticFlux::from_webers(1.0);
+        assert!((webers.as_milliwebers() - 1000.0).abs() < EPSILON);
+        assert!((webers.as_maxwells() - 1e8).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_magnetic_flux_density_conversion() {
+        let teslas = MagneticFluxDensity::from_teslas(1.0);
+        assert!((teslas.as_milliteslas() - 1000.0).abs() < EPSILON);
+        assert!((teslas.as_gauss() - 10000.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_torque_constant_conversion() {
+        let kt = TorqueConstant::from_newton_meters_per_ampere(1.0);
+        assert!((kt.as_pound_feet_per_ampere() - 1.0 / (4.4482216152605 * 0.3048)).abs() < EPSILON);
+        assert!((kt.as_ounce_inches_per_ampere() - 16.0 / (4.4482216152605 * 0.0254)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_velocity_constant_conversion() {
+        let kv = VelocityConstant::from_radians_per_second_per_volt(1.0);
+        assert!((kv.as_rotations_per_minute_per_volt() - 60.0 / (2.0 * std::f64::consts::PI)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_linear_momentum_conversion() {
+        let momentum = LinearMomentum::from_kilogram_meters_per_second(1.0);
+        assert!((momentum.as_pound_feet_per_second() - 1.0 / (0.45359237 * 0.3048)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_impulse_conversion() {
+        let impulse = Impulse::from_newton_seconds(1.0);
+        assert!((impulse.as_pound_force_seconds() - 1.0 / 4.4482216152605).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_jerk_conversion() {
+        let jerk = Jerk::from_meters_per_second_cubed(1.0);
+        assert!((jerk.as_feet_per_second_cubed() - 1.0 / 0.3048).abs() < EPSILON);
+        assert!((jerk.as_inches_per_second_cubed() - 1.0 / 0.0254).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angular_jerk_conversion() {
+        let jerk = AngularJerk::from_radians_per_second_cubed(1.0);
+        assert!((jerk.as_rotations_per_second_cubed() - 1.0 / (2.0 * std::f64::consts::PI)).abs() < EPSILON);
+        assert!((jerk.as_degrees_per_second_cubed() - 180.0 / std::f64::consts::PI).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_moment_of_inertia_conversion() {
+        let moi = MomentOfInertia::from_kilogram_square_meters(1.0);
+        assert!((moi.as_pound_square_feet() - 1.0 / (0.45359237 * 0.09290304)).abs() < EPSILON);
+        assert!((moi.as_pound_square_inches() - 1.0 / (0.45359237 * 0.00064516)).abs() < EPSILON);
+        assert!((MomentOfInertia::from_pound_square_feet(1.0).as_pound_square_inches() - 144.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angular_momentum_conversion() {
+        let momentum = AngularMomentum::from_kilogram_square_meters_per_second(1.0);
+        assert!((momentum.as_pound_square_feet_per_second() - 1.0 / (0.45359237 * 0.09290304)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_density_conversion() {
+        let density = Density::from_kilograms_per_cubic_meter(1000.0);
+        assert!((density.as_grams_per_cubic_centimeter() - 1.0).abs() < EPSILON);
+        assert!((density.as_pounds_per_cubic_foot() - 1000.0 * 0.028316846592 / 0.45359237).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_volumetric_flow_rate_conversion() {
+        let flow = VolumetricFlowRate::from_cubic_meters_per_second(1.0);
+        assert!((flow.as_liters_per_minute() - 60000.0).abs() < EPSILON);
+        assert!((flow.as_cubic_feet_per_minute() - 60.0 / 0.028316846592).abs() < EPSILON);
+        assert!((flow.as_us_gallons_per_minute() - 60.0 / 0.003785411784).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_mass_flow_rate_conversion() {
+        let flow = MassFlowRate::from_kilograms_per_second(1.0);
+        assert!((flow.as_grams_per_second() - 1000.0).abs() < EPSILON);
+        assert!((flow.as_kilograms_per_hour() - 3600.0).abs() < EPSILON);
+        assert!((flow.as_pounds_per_minute() - 60.0 / 0.45359237).abs() < EPSILON);
+        assert!((flow.as_pounds_per_hour() - 3600.0 / 0.45359237).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_viscosity_conversion() {
+        let dynamic = DynamicViscosity::from_pascal_seconds(1.0);
+        assert!((dynamic.as_poise() - 10.0).abs() < EPSILON);
+        assert!((dynamic.as_centipoise() - 1000.0).abs() < EPSILON);
+        let kinematic = KinematicViscosity::from_square_meters_per_second(1.0);
+        assert!((kinematic.as_stokes() - 10000.0).abs() < EPSILON);
+        assert!((kinematic.as_centistokes() - 1_000_000.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_heat_capacity_conversion() {
+        let capacity = HeatCapacity::from_joules_per_kelvin(1000.0);
+        assert!((capacity.as_kilojoules_per_kelvin() - 1.0).abs() < EPSILON);
+        let specific = SpecificHeatCapacity::from_btu_per_pound_fahrenheit(1.0);
+        assert!((specific.as_joules_per_kilogram_kelvin() - 4186.8).abs() < EPSILON);
+        assert!((specific.as_kilojoules_per_kilogram_kelvin() - 4.1868).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_thermal_conductivity_conversion() {
+        let conductivity = ThermalConductivity::from_btu_per_hour_foot_fahrenheit(1.0);
+        let expected = 1055.05585262 / (3600.0 * 0.3048 * 5.0 / 9.0);
+        assert!((conductivity.as_watts_per_meter_kelvin() - expected).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_entropy_conversion() {
+        let entropy = Entropy::from_kilojoules_per_kelvin(1.0);
+        assert!((entropy.as_joules_per_kelvin() - 1000.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_solid_angle_conversion() {
+        let steradians = SolidAngle::from_steradians(1.0);
+        assert!((steradians.as_square_degrees() - (180.0 / std::f64::consts::PI).powi(2)).abs() < EPSILON);
+        assert!((steradians.as_spats() - 1.0 / (4.0 * std::f64::consts::PI)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_illuminance_conversion() {
+        let lux = Illuminance::from_lux(1.0);
+        assert!((lux.as_foot_candles() - 0.09290304).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_luminance_conversion() {
+        let nits = Luminance::from_nits(1.0);
+        assert!((nits.as_foot_lamberts() - 0.09290304 * std::f64::consts::PI).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_irradiance_conversion() {
+        let irradiance = Irradiance::from_watts_per_square_meter(1000.0);
+        assert!((irradiance.as_milliwatts_per_square_centimeter() - 100.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_amount_of_substance_conversion() {
+        let moles = AmountOfSubstance::from_moles(1.0);
+        assert!((moles.as_kilomoles() - 0.001).abs() < EPSILON);
+        assert!((moles.as_millimoles() - 1000.0).abs() < EPSILON);
+        assert!((moles.as_micromoles() - 1_000_000.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_molar_mass_conversion() {
+        let molar_mass = MolarMass::from_grams_per_mole(18.015);
+        assert!((molar_mass.as_kilograms_per_mole() - 0.018015).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_reciprocal_amount_conversion() {
+        let reciprocal = ReciprocalAmount::from_per_millimole(1.0);
+        assert!((reciprocal.as_per_mole() - 1000.0).abs() < EPSILON);
+        let amount = 1.0 / ReciprocalAmount::from_per_mole(4.0);
+        assert!((amount.as_millimoles() - 250.0).abs() < EPSILON);
+        assert!((AmountOfSubstance::from_moles(2.0) * ReciprocalAmount::from_per_mole(3.0) - 6.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_molarity_conversion() {
+        let molarity = Molarity::from_moles_per_liter(1.0);
+        assert!((molarity.as_moles_per_cubic_meter() - 1000.0).abs() < EPSILON);
+        assert!((molarity.as_millimoles_per_liter() - 1000.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_catalytic_activity_conversion() {
+        let activity = CatalyticActivity::from_enzyme_units(60.0);
+        assert!((activity.as_katals() - 1e-6).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_information_conversion() {
+        let bytes = Information::from_bytes(1.0);
+        assert_eq!(bytes.as_bits(), 8.0);
+        assert_eq!(Information::from_kilobytes(1.0).as_bytes(), 1000.0);
+        assert_eq!(Information::from_kibibytes(1.0).as_bytes(), 1024.0);
+        assert_eq!(Information::from_mebibytes(1.0).as_kibibytes(), 1024.0);
+        assert_eq!(Information::from_gibibytes(1.0).as_mebibytes(), 1024.0);
+        assert_eq!(Information::from_megabits(1.0).as_kilobits(), 1000.0);
+        assert_eq!(Information::from_gigabits(1.0).as_megabytes(), 125.0);
+        assert_eq!(Information::from_mebibits(8.0).as_mebibytes(), 1.0);
+        assert_eq!(Information::from_kibibits(8.0).as_kibibytes(), 1.0);
+        assert_eq!(Information::from_gigabytes(1.0).as_bytes(), 1e9);
+    }
+
+    #[test]
+    fn test_data_rate_conversion() {
+        let rate = DataRate::from_megabits_per_second(1.0);
+        assert_eq!(rate.as_kilobits_per_second(), 1000.0);
+        assert_eq!(rate.as_kilobytes_per_second(), 125.0);
+        assert_eq!(rate.as_bytes_per_second(), 125000.0);
+        assert_eq!(DataRate::from_gigabits_per_second(1.0).as_megabytes_per_second(), 125.0);
+        assert_eq!(DataRate::from_mebibytes_per_second(1.0).as_kibibytes_per_second(), 1024.0);
+        assert_eq!(DataRate::from_bits_per_second(8.0).as_bytes_per_second(), 1.0);
+    }
+
+    #[test]
+    fn test_radioactivity_conversion() {
+        let curies = Radioactivity::from_curies(1.0);
+        assert!((curies.as_becquerels() - 3.7e10).abs() < EPSILON);
+        assert!((curies.as_millicuries() - 1000.0).abs() < EPSILON);
+        assert!((Radioactivity::from_kilobecquerels(37.0).as_microcuries() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_dose_conversion() {
+        let grays = AbsorbedDose::from_grays(1.0);
+        assert!((grays.as_milligrays() - 1000.0).abs() < EPSILON);
+        assert!((grays.as_rads() - 100.0).abs() < EPSILON);
+        let sieverts = EquivalentDose::from_sieverts(1.0);
+        assert!((sieverts.as_millisieverts() - 1000.0).abs() < EPSILON);
+        assert!((sieverts.as_microsieverts() - 1_000_000.0).abs() < EPSILON);
+        assert!((sieverts.as_rems() - 100.0).abs() < EPSILON);
+        assert!((sieverts.as_millirems() - 100_000.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_dose_rate_conversion() {
+        let rate = DoseRate::from_microsieverts_per_hour(1000.0);
+        assert!((rate.as_sieverts_per_hour() - 0.001).abs() < EPSILON);
+        assert!((rate.as_millirems_per_hour() - 100.0).abs() < EPSILON);
+        let absorbed = AbsorbedDoseRate::from_grays_per_hour(1.0);
+        assert!((absorbed.as_milligrays_per_hour() - 1000.0).abs() < EPSILON);
+        assert!((absorbed.as_grays_per_second() - 1.0 / 3600.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_calendar_time_conversion() {
+        assert_eq!(Time::from_weeks(2.0), Time::from_fortnights(1.0));
+        assert_eq!(Time::from_julian_years(1.0).as_days(), 365.25);
+        assert_eq!(Time::from_gregorian_years(1.0).as_days(), 365.2425);
+        assert!((Time::from_tropical_years(1.0).as_days() - 365.24219).abs() < EPSILON);
+        assert_eq!(Time::from_months(12.0), Time::from_gregorian_years(1.0));
+        assert_eq!(Time::from_years(1.0).as_days(), 365.0);
+    }
+
+    #[test]
+    fn test_exact_distance_relationships() {
+        assert_eq!(Distance::from_feet(1.0).as_inches(), 12.0);
+        assert_eq!(Distance::from_inches(12.0), Distance::from_feet(1.0));
+        assert_eq!(Distance::from_feet(5280.0), Distance::from_miles(1.0));
+        assert_eq!(Distance::from_miles(1.0).as_feet(), 5280.0);
+        assert_eq!(Distance::from_miles(1.0).as_inches(), 63360.0);
+        assert_eq!(Distance::from_feet(1.0).as_meters(), 0.3048);
+        assert_eq!(Distance::from_furlongs(8.0), Distance::from_miles(1.0));
+    }
+
+    #[test]
+    fn test_exact_round_trips() {
+        for value in [0.5, 1.0, 3.0, 12.0, 5280.0] {
+            assert_eq!(Distance::from_feet(value).as_feet(), value);
+            assert_eq!(Mass::from_pounds(value).as_pounds(), value);
+            assert_eq!(Force::from_pounds_force(value).as_pounds_force(), value);
+        }
+    }
+
+    #[test]
+    fn test_exact_unit_definitions() {
+        assert_eq!(Mass::from_pounds(1.0).as_kilograms(), 0.45359237);
+        assert_eq!(Mass::from_pounds(1.0).as_ounces(), 16.0);
+        assert_eq!(Mass::from_stones(1.0).as_pounds(), 14.0);
+        assert_eq!(Force::from_pounds_force(1.0).as_newtons(), 4.4482216152605);
+        assert_eq!(Angle::from_rotations(1.0).as_radians(), 2.0 * std::f64::consts::PI);
+        assert_eq!(Angle::from_rotations(1.0).as_degrees(), 360.0);
+        assert_eq!(Time::from_days(1.0).as_hours(), 24.0);
+        assert_eq!(LinearVelocity::from_miles_per_hour(60.0).as_feet_per_second(), 88.0);
+    }
+
+    #[test]
+    fn test_linear_velocity_from_distance_and_time() {
+        let distance = Distance::from_meters(100.0);
+        let time = Time::from_seconds(10.0);
+        let velocity = distance / time;
+        assert!((velocity.as_meters_per_second() - 10.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angular_velocity_from_angle_and_time() {
+        let angle = Angle::from_radians(2.0 * std::f64::consts::PI);
+        let time = Time::from_seconds(1.0);
+        let angular_velocity = angle / time;
+        assert!((angular_velocity.as_rotations_per_second() - 1.0).abs() < EPSILON, "{}", angular_velocity.as_rotations_per_second());
+    }
+
+    #[test]
+    fn test_torque_from_force_and_distance() {
+        let force = Force::from_newtons(10.0);
+        let distance = Distance::from_meters(2.0);
+        let torque = force * distance;
+        assert!((torque.as_newton_meters() - 20.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_power_from_energy_and_time() {
+        let energy = Energy::from_joules(100.0);
+        let time = Time::from_seconds(10.0);
+        let power = energy / time;
+        assert!((power.as_watts() - 10.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_current_from_power_and_electric_potential() {
+        let power = Power::from_watts(100.0);
+        let voltage = ElectricPotential::from_volts(10.0);
+        let current = power / voltage;
+        assert!((current.as_amperes() - 10.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_electric_potential_from_power_and_current() {
+        let power = Power::from_watts(100.0);
+        let current = Current::from_amperes(10.0);
+        let voltage = power / current;
+        assert!((voltage.as_volts() - 10.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_power_from_electric_potential_and_current() {
+        let voltage = ElectricPotential::from_volts(10.0);
+        let current = Current::from_amperes(10.0);
+        let power = voltage * current;
+        assert!((power.as_watts() - 100.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_area_from_distance_and_distance() {
+        let area = Distance::from_feet(10.0) * Distance::from_feet(20.0);
+        assert!((area.as_square_feet() - 200.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_volume_from_area_and_distance() {
+        let volume = Area::from_square_meters(2.0) * Distance::from_meters(0.5);
+        assert!((volume.as_liters() - 1000.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_distance_from_volume_and_area() {
+        let distance = Volume::from_cubic_inches(24.0) / Area::from_square_inches(4.0);
+        assert!((distance.as_inches() - 6.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_pressure_from_force_and_area() {
+        let pressure = Force::from_pounds_force(100.0) / Area::from_square_inches(2.0);
+        assert!((pressure.as_psi() - 50.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_force_from_pressure_and_area() {
+        let force = Pressure::from_pascals(1000.0) * Area::from_square_meters(0.5);
+        assert!((force.as_newtons() - 500.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_frequency_from_time() {
+        let frequency = 1.0 / Time::from_seconds(0.02);
+        assert!((frequency.as_hertz() - 50.0).abs() < EPSILON);
+        let period = 1.0 / Frequency::from_kilohertz(1.0);
+        assert!((period.as_seconds() - 0.001).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_cycles_from_frequency_and_time() {
+        let cycles = Frequency::from_per_minute(120.0) * Time::from_minutes(2.0);
+        assert!((cycles - 240.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angular_velocity_from_angle_and_frequency() {
+        let angular_velocity = Angle::from_degrees(90.0) * Frequency::from_hertz(4.0);
+        assert!((angular_velocity.as_rotations_per_second() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angular_velocity_and_frequency() {
+        let angular_velocity = AngularVelocity::from_frequency(Frequency::from_hertz(2.0));
+        assert!((angular_velocity.as_rotations_per_minute() - 120.0).abs() < EPSILON);
+        let frequency = AngularVelocity::from_rotations_per_minute(600.0).as_frequency();
+        assert!((frequency.as_hertz() - 10.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_resistance_from_electric_potential_and_current() {
+        let resistance = ElectricPotential::from_volts(12.0) / Current::from_milliamperes(20.0);
+        assert!((resistance.as_ohms() - 600.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_electric_potential_from_current_and_resistance() {
+        let voltage = Current::from_milliamperes(2.0) * Resistance::from_kiloohms(4.7);
+        assert!((voltage.as_volts() - 9.4).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_conductance_and_resistance() {
+        let conductance = 1.0 / Resistance::from_kiloohms(1.0);
+        assert!((conductance.as_millisiemens() - 1.0).abs() < EPSILON);
+        let resistance = 1.0 / Conductance::from_siemens(4.0);
+        assert!((resistance.as_ohms() - 0.25).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_charge_from_current_and_time() {
+        let charge = Current::from_amperes(2.0) * Time::from_hours(1.5);
+        assert!((charge.as_ampere_hours() - 3.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_energy_from_charge_and_electric_potential() {
+        let energy = Charge::from_ampere_hours(2.0) * ElectricPotential::from_volts(12.0);
+        assert!((energy.as_watt_hours() - 24.0).abs() < EPSILON);
+        let voltage = energy / Charge::from_ampere_hours(2.0);
+        assert!((voltage.as_volts() - 12.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_time_from_resistance_and_capacitance() {
+        let time_constant = Resistance::from_kiloohms(10.0) * Capacitance::from_microfarads(100.0);
+        assert!((time_constant.as_seconds() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_magnetic_flux_from_electric_potential_and_time() {
+        let flux = ElectricPotential::from_volts(2.0) * Time::from_seconds(3.0);
+        assert!((flux.as_webers() - 6.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_magnetic_flux_density_and_area() {
+        let density = MagneticFlux::from_milliwebers(2.0) / Area::from_square_meters(0.004);
+        assert!((density.as_teslas() - 0.5).abs() < EPSILON);
+        let flux = MagneticFluxDensity::from_gauss(10000.0) * Area::from_square_meters(2.0);
+        assert!((flux.as_webers() - 2.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_torque_from_torque_constant_and_current() {
+        let torque = TorqueConstant::from_newton_meters_per_ampere(0.0182) * Current::from_amperes(100.0);
+        assert!((torque.as_newton_meters() - 1.82).abs() < EPSILON);
+        let kt = Torque::from_newton_meters(4.69) / Current::from_amperes(257.0);
+        assert!((kt.as_newton_meters_per_ampere() - 4.69 / 257.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angular_velocity_from_velocity_constant_and_electric_potential() {
+        let angular_velocity = VelocityConstant::from_rotations_per_minute_per_volt(500.0) * ElectricPotential::from_volts(12.0);
+        assert!((angular_velocity.as_rotations_per_minute() - 6000.0).abs() < EPSILON);
+        let kv = AngularVelocity::from_rotations_per_minute(6000.0) / ElectricPotential::from_volts(12.0);
+        assert!((kv.as_rotations_per_minute_per_volt() - 500.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_force_from_mass_and_linear_acceleration() {
+        let force = Mass::from_kilograms(2.0) * crate::constants::STANDARD_GRAVITY;
+        assert!((force.as_newtons() - 19.6133).abs() < EPSILON);
+        let acceleration = Force::from_newtons(10.0) / Mass::from_kilograms(4.0);
+        assert!((acceleration.as_meters_per_second_squared() - 2.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_impulse_from_force_and_time() {
+        let impulse = Force::from_newtons(50.0) * Time::from_seconds(0.2);
+        assert!((impulse.as_newton_seconds() - 10.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_linear_momentum_from_mass_and_linear_velocity() {
+        let momentum = Mass::from_kilograms(3.0) * LinearVelocity::from_meters_per_second(4.0);
+        assert!((momentum.as_kilogram_meters_per_second() - 12.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_jerk_from_linear_acceleration_and_time() {
+        let jerk = LinearAcceleration::from_meters_per_second_squared(6.0) / Time::from_seconds(2.0);
+        assert!((jerk.as_meters_per_second_cubed() - 3.0).abs() < EPSILON);
+        let angular_jerk = AngularAcceleration::from_radians_per_second_squared(6.0) / Time::from_seconds(2.0);
+        assert!((angular_jerk.as_radians_per_second_cubed() - 3.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_torque_from_moment_of_inertia_and_angular_acceleration() {
+        let moi = Mass::from_kilograms(2.0) * Area::from_square_meters(0.5);
+        let torque = moi * AngularAcceleration::from_radians_per_second_squared(3.0);
+        assert!((torque.as_newton_meters() - 3.0).abs() < EPSILON);
+        let acceleration = torque / moi;
+        assert!((acceleration.as_radians_per_second_squared() - 3.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angular_momentum_from_moment_of_inertia_and_angular_velocity() {
+        let momentum = MomentOfInertia::from_kilogram_square_meters(0.5) * AngularVelocity::from_radians_per_second(4.0);
+        assert!((momentum.as_kilogram_square_meters_per_second() - 2.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_energy_from_torque_and_angle() {
+        let energy = Torque::from_newton_meters(10.0) * Angle::from_rotations(1.0);
+        assert!((energy.as_joules() - 20.0 * std::f64::consts::PI).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_density_from_mass_and_volume() {
+        let density = Mass::from_kilograms(2.0) / Volume::from_liters(1.0);
+        assert!((density.as_kilograms_per_cubic_meter() - 2000.0).abs() < EPSILON);
+        let mass = density * Volume::from_liters(3.0);
+        assert!((mass.as_kilograms() - 6.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_flow_rates_from_time() {
+        let flow = Volume::from_liters(30.0) / Time::from_minutes(2.0);
+        assert!((flow.as_liters_per_minute() - 15.0).abs() < EPSILON);
+        assert!(((flow * Time::from_minutes(4.0)).as_liters() - 60.0).abs() < EPSILON);
+        let mass_flow = Mass::from_kilograms(7.2) / Time::from_hours(1.0);
+        assert!((mass_flow.as_kilograms_per_hour() - 7.2).abs() < EPSILON);
+        assert!(((mass_flow * Time::from_hours(2.0)).as_kilograms() - 14.4).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_mass_flow_rate_from_density_and_volumetric_flow_rate() {
+        let density = Density::from_kilograms_per_cubic_meter(1.2);
+        let mass_flow = density * VolumetricFlowRate::from_liters_per_minute(500.0);
+        assert!((mass_flow.as_grams_per_second() - 10.0).abs() < EPSILON);
+        let flow = mass_flow / density;
+        assert!((flow.as_liters_per_minute() - 500.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_power_from_pressure_and_volumetric_flow_rate() {
+        let power = Pressure::from_bar(6.0) * VolumetricFlowRate::from_liters_per_minute(100.0);
+        assert!((power.as_watts() - 1000.0).abs() < EPSILON);
+        let pressure = power / VolumetricFlowRate::from_liters_per_minute(100.0);
+        assert!((pressure.as_bar() - 6.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_viscosity_relationships() {
+        let dynamic = Pressure::from_pascals(0.5) * Time::from_seconds(0.002);
+        assert!((dynamic.as_centipoise() - 1.0).abs() < EPSILON);
+        let kinematic = dynamic / Density::from_kilograms_per_cubic_meter(1000.0);
+        assert!((kinematic.as_centistokes() - 1.0).abs() < EPSILON);
+        let dynamic = kinematic * Density::from_kilograms_per_cubic_meter(1000.0);
+        assert!((dynamic.as_pascal_seconds() - 0.001).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_temperature_delta_from_power_and_thermal_resistance() {
+        let rise = Power::from_watts(40.0) * ThermalResistance::from_kelvin_per_watt(1.5);
+        assert!((rise.as_celsius() - 60.0).abs() < EPSILON);
+        let winding = Temperature::from_celsius(25.0) + rise;
+        assert!((winding.as_celsius() - 85.0).abs() < EPSILON);
+        let power = rise / ThermalResistance::from_kelvin_per_watt(1.5);
+        assert!((power.as_watts() - 40.0).abs() < EPSILON);
+        let resistance = rise / Power::from_watts(40.0);
+        assert!((resistance.as_kelvin_per_watt() - 1.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_heat_capacity_relationships() {
+        let capacity = Mass::from_kilograms(2.0) * SpecificHeatCapacity::from_joules_per_kilogram_kelvin(900.0);
+        assert!((capacity.as_joules_per_kelvin() - 1800.0).abs() < EPSILON);
+        let energy = capacity * TemperatureDelta::from_kelvin(10.0);
+        assert!((energy.as_kilojoules() - 18.0).abs() < EPSILON);
+        assert!(((energy / TemperatureDelta::from_kelvin(10.0)).as_joules_per_kelvin() - 1800.0).abs() < EPSILON);
+        assert!(((capacity / Mass::from_kilograms(2.0)).as_joules_per_kilogram_kelvin() - 900.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_entropy_from_energy_and_temperature() {
+        let entropy = Energy::from_joules(600.0) / Temperature::from_kelvin(300.0);
+        assert!((entropy.as_joules_per_kelvin() - 2.0).abs() < EPSILON);
+        let energy = entropy * Temperature::from_kelvin(300.0);
+        assert!((energy.as_joules() - 600.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_solid_angle_of_cone() {
+        assert!((SolidAngle::of_cone(Angle::from_degrees(90.0)).as_spats() - 0.5).abs() < EPSILON);
+        assert!((SolidAngle::of_cone(Angle::from_degrees(180.0)).as_spats() - 1.0).abs() < EPSILON);
+        let narrow = SolidAngle::of_cone(Angle::from_radians(0.5));
+        assert!((narrow.as_steradians() - 2.0 * PI * (1.0 - 0.5_f64.cos())).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_luminous_flux_from_luminous_intensity_and_solid_angle() {
+        let flux = LuminousIntensity::from_candelas(100.0) * SolidAngle::from_spats(1.0);
+        assert!((flux.as_lumens() - 400.0 * std::f64::consts::PI).abs() < EPSILON);
+        let intensity = flux / SolidAngle::from_spats(1.0);
+        assert!((intensity.as_candelas() - 100.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_illuminance_from_luminous_flux_and_area() {
+        let illuminance = LuminousFlux::from_lumens(800.0) / Area::from_square_meters(2.0);
+        assert!((illuminance.as_lux() - 400.0).abs() < EPSILON);
+        let flux = Illuminance::from_foot_candles(10.0) * Area::from_square_feet(5.0);
+        assert!((flux.as_lumens() - 50.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_luminance_from_luminous_intensity_and_area() {
+        let luminance = LuminousIntensity::from_candelas(50.0) / Area::from_square_meters(0.1);
+        assert!((luminance.as_nits() - 500.0).abs() < EPSILON);
+        let intensity = luminance * Area::from_square_meters(0.1);
+        assert!((intensity.as_candelas() - 50.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_radiometric_relationships() {
+        let intensity = Power::from_watts(10.0) / SolidAngle::from_steradians(2.0);
+        assert!((intensity.as_watts_per_steradian() - 5.0).abs() < EPSILON);
+        assert!(((intensity * SolidAngle::from_steradians(2.0)).as_watts() - 10.0).abs() < EPSILON);
+        let irradiance = Power::from_watts(10.0) / Area::from_square_meters(4.0);
+        assert!((irradiance.as_watts_per_square_meter() - 2.5).abs() < EPSILON);
+        assert!(((irradiance * Area::from_square_meters(4.0)).as_watts() - 10.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_amount_of_substance_from_mass_and_molar_mass() {
+        let amount = Mass::from_grams(36.03) / MolarMass::from_grams_per_mole(18.015);
+        assert!((amount.as_moles() - 2.0).abs() < EPSILON);
+        let mass = amount * MolarMass::from_grams_per_mole(18.015);
+        assert!((mass.as_grams() - 36.03).abs() < EPSILON);
+        let molar_mass = mass / amount;
+        assert!((molar_mass.as_grams_per_mole() - 18.015).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_molarity_from_amount_of_substance_and_volume() {
+        let molarity = AmountOfSubstance::from_millimoles(250.0) / Volume::from_liters(0.5);
+        assert!((molarity.as_moles_per_liter() - 0.5).abs() < EPSILON);
+        let amount = molarity * Volume::from_liters(2.0);
+        assert!((amount.as_moles() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_catalytic_activity_from_amount_of_substance_and_time() {
+        let activity = AmountOfSubstance::from_micromoles(120.0) / Time::from_minutes(2.0);
+        assert!((activity.as_enzyme_units() - 60.0).abs() < EPSILON);
+        let amount = activity * Time::from_minutes(1.0);
+        assert!((amount.as_micromoles() - 60.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_data_rate_from_information_and_time() {
+        let rate = Information::from_bytes(8.0) * 125_000.0 / Time::from_seconds(1.0);
+        assert_eq!(rate.as_megabits_per_second(), 8.0);
+        let time = Information::from_megabytes(10.0) / DataRate::from_megabits_per_second(8.0);
+        assert_eq!(time.as_seconds(), 10.0);
+        let information = DataRate::from_kilobits_per_second(500.0) * Time::from_seconds(2.0);
+        assert_eq!(information.as_kilobytes(), 125.0);
+    }
+
+    #[test]
+    fn test_decays_from_radioactivity_and_time() {
+        let decays = Radioactivity::from_kilobecquerels(2.0) * Time::from_minutes(1.0);
+        assert!((decays - 120_000.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_absorbed_dose_from_energy_and_mass() {
+        let dose = Energy::from_joules(0.7) / Mass::from_kilograms(70.0);
+        assert!((dose.as_milligrays() - 10.0).abs() < EPSILON);
+        let energy = dose * Mass::from_kilograms(70.0);
+        assert!((energy.as_joules() - 0.7).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_equivalent_dose_from_absorbed_dose() {
+        let dose = AbsorbedDose::from_milligrays(2.0);
+        assert!((dose.to_equivalent_dose(1.0).as_millisieverts() - 2.0).abs() < EPSILON);
+        assert!((dose.to_equivalent_dose(20.0).as_millisieverts() - 40.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_dose_rates_and_time() {
+        let rate = EquivalentDose::from_microsieverts(10.0) / Time::from_hours(4.0);
+        assert!((rate.as_microsieverts_per_hour() - 2.5).abs() < EPSILON);
+        assert!(((rate * Time::from_hours(8.0)).as_microsieverts() - 20.0).abs() < EPSILON);
+        let absorbed_rate = AbsorbedDose::from_grays(2.0) / Time::from_hours(1.0);
+        assert!((absorbed_rate.as_grays_per_hour() - 2.0).abs() < EPSILON);
+        assert!(((absorbed_rate * Time::from_minutes(30.0)).as_grays() - 1.0).abs() < EPSILON);
+    }
+
+    // Only the `from_*` half of the derived conversions is needed to compare factors.
+    #[allow(dead_code)]
+    mod derived {
+        use super::super::*;
+        use crate::{create_derived_converters, create_unit};
+
+        create_unit!(DerivedLinearVelocity, base_units => 1.0);
+        create_unit!(DerivedAngularVelocity, base_units => 1.0);
+        create_unit!(DerivedMomentOfInertia, base_units => 1.0);
+        create_unit!(DerivedDensity, base_units => 1.0);
+        create_unit!(DerivedPressure, base_units => 1.0);
+
+        create_derived_converters!(
+            Distance / Time => DerivedLinearVelocity,
+            meters / seconds => meters_per_second,
+            feet / seconds => feet_per_second,
+            inches / seconds => inches_per_second,
+            miles / hours => miles_per_hour,
+            kilometers / hours => kilometers_per_hour,
+            nautical_miles / hours => knots,
+            furlongs / fortnights => furlongs_per_fortnight
+        );
+        create_derived_converters!(
+            Angle / Time => DerivedAngularVelocity,
+            radians / seconds => radians_per_second,
+            rotations / seconds => rotations_per_second,
+            rotations / minutes => rotations_per_minute,
+            degrees / seconds => degrees_per_second
+        );
+        create_derived_converters!(
+            Mass * Area => DerivedMomentOfInertia,
+            kilograms * square_meters => kilogram_square_meters,
+            pounds * square_feet => pound_square_feet,
+            pounds * square_inches => pound_square_inches
+        );
+        create_derived_converters!(
+            Mass / Volume => DerivedDensity,
+            kilograms / cubic_meters => kilograms_per_cubic_meter
+        );
+        create_derived_converters!(
+            Force / Area => DerivedPressure,
+            pounds_force / square_inches => pounds_force_per_square_inch
+        );
+    }
+
+    fn assert_factors_agree(handwritten: f64, derived: f64) {
+        assert!(((handwritten - derived) / derived).abs() < 1e-12, "{handwritten} != {derived}");
+    }
+
+    #[test]
+    fn test_linear_velocity_factors_agree_with_distance_and_time() {
+        use derived::DerivedLinearVelocity as Derived;
+        assert_factors_agree(LinearVelocity::from_meters_per_second(1.0).0, Derived::from_meters_per_second(1.0).0);
+        assert_factors_agree(LinearVelocity::from_feet_per_second(1.0).0, Derived::from_feet_per_second(1.0).0);
+        assert_factors_agree(LinearVelocity::from_inches_per_second(1.0).0, Derived::from_inches_per_second(1.0).0);
+        assert_factors_agree(LinearVelocity::from_miles_per_hour(1.0).0, Derived::from_miles_per_hour(1.0).0);
+        assert_factors_agree(LinearVelocity::from_kilometers_per_hour(1.0).0, Derived::from_kilometers_per_hour(1.0).0);
+        assert_factors_agree(LinearVelocity::from_knots(1.0).0, Derived::from_knots(1.0).0);
+        assert_factors_agree(LinearVelocity::from_furlongs_per_fortnight(1.0).0, Derived::from_furlongs_per_fortnight(1.0).0);
+        assert_factors_agree(LinearVelocity::from_miles_per_hour(60.0).as_feet_per_second(), Derived(LinearVelocity::from_miles_per_hour(60.0).0).as_feet_per_second());
+    }
+
+    #[test]
+    fn test_angular_velocity_factors_agree_with_angle_and_time() {
+        use derived::DerivedAngularVelocity as Derived;
+        assert_factors_agree(AngularVelocity::from_radians_per_second(1.0).0, Derived::from_radians_per_second(1.0).0);
+        assert_factors_agree(AngularVelocity::from_rotations_per_second(1.0).0, Derived::from_rotations_per_second(1.0).0);
+        assert_factors_agree(AngularVelocity::from_rotations_per_minute(1.0).0, Derived::from_rotations_per_minute(1.0).0);
+        assert_factors_agree(AngularVelocity::from_degrees_per_second(1.0).0, Derived::from_degrees_per_second(1.0).0);
+    }
+
+    #[test]
+    fn test_product_factors_agree_with_operands() {
+        use derived::{DerivedDensity, DerivedMomentOfInertia, DerivedPressure};
+        assert_factors_agree(MomentOfInertia::from_kilogram_square_meters(1.0).0, DerivedMomentOfInertia::from_kilogram_square_meters(1.0).0);
+        assert_factors_agree(MomentOfInertia::from_pound_square_feet(1.0).0, DerivedMomentOfInertia::from_pound_square_feet(1.0).0);
+        assert_factors_agree(MomentOfInertia::from_pound_square_inches(1.0).0, DerivedMomentOfInertia::from_pound_square_inches(1.0).0);
+        assert_factors_agree(Density::from_kilograms_per_cubic_meter(1.0).0, DerivedDensity::from_kilograms_per_cubic_meter(1.0).0);
+        assert_factors_agree(Pressure::from_psi(1.0).0, DerivedPressure::from_pounds_force_per_square_inch(1.0).0);
+    }
+
+    #[test]
+    fn test_derived_units() {
+        let pressure = Force::from_pounds_force(10.0) / Area::from_square_feet(2.0);
+        assert!((pressure.as_pounds_force_per_square_foot() - 5.0).abs() < EPSILON);
+        assert!((Pressure::from_pounds_force_per_square_foot(144.0).as_psi() - 1.0).abs() < EPSILON);
+        let flow = Volume::from_liters(90.0) / Time::from_minutes(1.0);
+        assert!((flow.as_liters_per_second() - 1.5).abs() < EPSILON);
+        assert!((flow.as_liters_per_minute() - 90.0).abs() < EPSILON);
+        assert!((VolumetricFlowRate::from_imperial_gallons_per_minute(1.0).as_liters_per_minute() - 4.54609).abs() < EPSILON);
+        let mass_flow = Mass::from_kilograms(120.0) / Time::from_hours(1.0);
+        assert!((mass_flow.as_kilograms_per_minute() - 2.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_rearranged_operations() {
+        let velocity = LinearVelocity::from_meters_per_second(5.0);
+        let time = Time::from_seconds(4.0);
+        assert!(((velocity * time).as_meters() - 20.0).abs() < EPSILON);
+        assert!(((time * velocity).as_meters() - 20.0).abs() < EPSILON);
+        assert!(((Distance::from_meters(20.0) / velocity).as_seconds() - 4.0).abs() < EPSILON);
+
+        let power = Power::from_watts(120.0);
+        let current = Current::from_amperes(10.0);
+        let voltage = ElectricPotential::from_volts(12.0);
+        assert!(((power / current).as_volts() - 12.0).abs() < EPSILON);
+        assert!(((power / voltage).as_amperes() - 10.0).abs() < EPSILON);
+        assert!(((current * voltage).as_watts() - 120.0).abs() < EPSILON);
+
+        let area = Area::from_square_meters(6.0);
+        assert!(((area / Distance::from_meters(2.0)).as_meters() - 3.0).abs() < EPSILON);
+        assert!(((Time::from_seconds(0.5) * Frequency::from_hertz(4.0)) - 2.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_specific_energy_conversion() {
+        let specific_energy = SpecificEnergy::from_btu_per_pound(1.0);
+        assert!((specific_energy.as_kilojoules_per_kilogram() - 2.326).abs() < EPSILON);
+        assert!((SpecificEnergy::from_megajoules_per_kilogram(1.0).as_joules_per_kilogram() - 1e6).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_powers_and_roots() {
+        use crate::dimension::{Hypot, Powi};
+
+        let side = Distance::from_meters(3.0);
+        assert!((side.squared().as_square_meters() - 9.0).abs() < EPSILON);
+        assert!((side.cubed().as_cubic_meters() - 27.0).abs() < EPSILON);
+        assert!((Powi::<2>::powi(&side).as_square_meters() - 9.0).abs() < EPSILON);
+        assert!((Powi::<3>::powi(&side).as_cubic_meters() - 27.0).abs() < EPSILON);
+        assert!((Area::from_square_feet(4.0).sqrt().as_feet() - 2.0).abs() < EPSILON);
+        assert!((Volume::from_cubic_meters(8.0).cbrt().as_meters() - 2.0).abs() < EPSILON);
+        assert!((Distance::from_meters(3.0).hypot(Distance::from_meters(4.0)).as_meters() - 5.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_kinetic_energy() {
+        use crate::dimension::Hypot;
+
+        let mass = Mass::from_kilograms(2.0);
+        let velocity = LinearVelocity::from_meters_per_second(3.0);
+        let energy = mass * velocity.squared() * 0.5;
+        assert!((energy.as_joules() - 9.0).abs() < EPSILON);
+        assert!(((energy / velocity.squared()).as_kilograms() - 1.0).abs() < EPSILON);
+        assert!((SpecificEnergy::from_joules_per_kilogram(9.0).sqrt().as_meters_per_second() - 3.0).abs() < EPSILON);
+        let ground_speed = velocity.hypot(LinearVelocity::from_meters_per_second(4.0));
+        assert!((ground_speed.as_meters_per_second() - 5.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_gravitational_units() {
+        let standard_gravity = crate::constants::STANDARD_GRAVITY.as_meters_per_second_squared();
+        assert_eq!(Force::from_kilograms_force(1.0).as_newtons(), standard_gravity);
+        assert!((Force::from_pounds_force(1.0).as_newtons() - Mass::from_pounds(1.0).as_kilograms() * standard_gravity).abs() < 1e-15);
+        let slug_acceleration = LinearAcceleration::from_feet_per_second_squared(1.0).as_meters_per_second_squared();
+        assert!((Mass::from_slugs(1.0).as_kilograms() * slug_acceleration - Force::from_pounds_force(1.0).as_newtons()).abs() < 1e-15);
+        let force = Mass::from_slugs(1.0) * LinearAcceleration::from_feet_per_second_squared(1.0);
+        assert!((force.as_pounds_force() - 1.0).abs() < EPSILON);
+        assert!((Mass::from_slugs(1.0).as_kilograms() - 14.593902937206364).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_weight_and_mass_equivalent() {
+        assert!((Mass::from_pounds(1.0).standard_weight().as_pounds_force() - 1.0).abs() < EPSILON);
+        assert!((Mass::from_kilograms(1.0).standard_weight().as_kilograms_force() - 1.0).abs() < EPSILON);
+        assert!((Force::from_pounds_force(10.0).standard_mass_equivalent().as_pounds() - 10.0).abs() < EPSILON);
+
+        let moon = LinearAcceleration::from_meters_per_second_squared(1.62);
+        let weight = Mass::from_kilograms(80.0).weight(moon);
+        assert!((weight.as_newtons() - 129.6).abs() < EPSILON);
+        assert!((weight.mass_equivalent(moon).as_kilograms() - 80.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_local_gravity() {
+        let sea_level = Distance::from_meters(0.0);
+        let equator = LinearAcceleration::local_gravity(Angle::from_degrees(0.0), sea_level);
+        let pole = LinearAcceleration::local_gravity(Angle::from_degrees(90.0), sea_level);
+        assert!((equator.as_meters_per_second_squared() - 9.7803253359).abs() < EPSILON);
+        assert!((pole.as_meters_per_second_squared() - 9.8321849378).abs() < 1e-4);
+        let mid_latitude = LinearAcceleration::local_gravity(Angle::from_degrees(45.0), sea_level);
+        assert!((mid_latitude - crate::constants::STANDARD_GRAVITY).as_meters_per_second_squared().abs() < 1e-3);
+        let mountain = LinearAcceleration::local_gravity(Angle::from_degrees(45.0), Distance::from_meters(1000.0));
+        assert!(mountain.as_meters_per_second_squared() < mid_latitude.as_meters_per_second_squared());
+    }
+}
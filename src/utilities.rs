@@ -4,12 +4,16 @@
 //!
 //! ## `create_converters`
 //! 
-//! This macro generates conversion methods for a given unit structure. It takes a structure name and a list of unit names with their conversion factors.
+//! This macro generates conversion methods for a given unit structure. It takes a structure name and a list of unit names with their definitions,
+//! each given as the number of base units in one of that unit. A definition may be written as a ratio (`inch => 254.0 / 10000.0`), which keeps
+//! exact decimal definitions exact when converting between units; the macro computes the reciprocals itself.
 //!
 //! ### Example
 //!
 //! ```rust
-//! create_converters!(Length, meter => 1.0, kilometer => 1000.0);
+//! # use rusty_units::create_converters;
+//! # struct Length(f64);
+//! create_converters!(Length, meter => 1.0, kilometer => 1000.0, foot => 3048.0 / 10000.0);
 //! ```
 //!
 //! This will generate methods like `as_meter`, `from_meter`, `as_kilometer`, and `from_kilometer` for the `Length` struct.
@@ -21,10 +25,13 @@
 //! ### Example
 //!
 //! ```rust
+//! # use rusty_units::create_operations;
+//! # #[derive(Debug, Clone, Copy)]
+//! # struct Length(f64);
 //! create_operations!(Length);
 //! ```
 //!
-//! This will enable addition, subtraction, multiplication by a scalar, and division by a scalar for the `Length` struct.
+//! This will enable addition, subtraction, multiplication by a scalar, and division by a scalar for the `Length` struct, along with a `hypot` method.
 //!
 //! ## `create_dimension`
 //!
@@ -33,23 +40,34 @@
 //! ### Example
 //!
 //! ```rust
+//! # use rusty_units::create_dimension;
+//! # struct Length(f64);
 //! create_dimension!(Length);
 //! ```
 //!
-//! This will implement the `Dimension` trait for the `Length` struct, providing methods `as_base_units` and `from_base_units`.
+//! This will implement the `Dimension` trait for the `Length` struct, providing methods `as_base_units` and `from_base_units`, along with a `powi` method
+//! for any powers of `Length` registered with `create_unit_operations`.
 //!
 //! ## `create_unit_operations`
 //!
-//! This macro generates multiplication and division operations between two different unit structures, resulting in a third unit structure.
+//! This macro generates multiplication and division operations between unit structures from a single relationship, including every rearrangement of it.
 //!
 //! ### Example
 //!
 //! ```rust
-//! create_unit_operations!(Length * Time => Speed);
+//! # use rusty_units::create_unit_operations;
+//! # struct Length(f64);
+//! # struct Time(f64);
+//! # struct Speed(f64);
 //! create_unit_operations!(Length / Time => Speed);
 //! ```
 //!
-//! This will enable multiplication and division between `Length` and `Time` structs, resulting in a `Speed` struct.
+//! This will enable division of a `Length` by a `Time`, resulting in a `Speed` struct, multiplication of a `Speed` by a `Time` in either order, resulting in a `Length` struct,
+//! and division of a `Length` by a `Speed`, resulting in a `Time` struct.
+//!
+//! ## `create_single_unit_operation`
+//!
+//! This macro generates a single multiplication or division operation, for relationships that should only be defined one way.
 //!
 //! ## `create_unit`
 //!
@@ -58,22 +76,89 @@
 //! ### Example
 //!
 //! ```rust
+//! # use rusty_units::create_unit;
 //! create_unit!(Length, meter => 1.0, kilometer => 1000.0);
 //! ```
 //!
 //! This will generate a `Length` struct with conversion methods, arithmetic operations, and the `Dimension` trait implementation.
+//!
+//! ## `create_derived_converters`
+//!
+//! This macro generates conversion methods for the result of a unit operation, deriving each unit and its conversion factor from a pair of units of the operands.
+//!
+//! ### Example
+//!
+//! ```rust
+//! # use rusty_units::{create_derived_converters, create_unit};
+//! # create_unit!(Length, meters => 1.0, feet => 0.3048);
+//! # create_unit!(Time, seconds => 1.0);
+//! # create_unit!(Speed, meters_per_second => 1.0);
+//! create_derived_converters!(Length / Time => Speed, feet / seconds => feet_per_second);
+//! ```
+//!
+//! This will generate methods like `as_feet_per_second` and `from_feet_per_second` for the `Speed` struct. Each derived unit is named explicitly,
+//! as the plural units of the operands don't give the singular name of the derived unit, ie `feet / seconds` is `feet_per_second`.
+//!
+//! ## `create_kind`
+//!
+//! This macro implements the `Kind` trait for a given unit structure, tagging it with the exponents of its SI base dimensions.
+//!
+//! ### Example
+//!
+//! ```rust
+//! # use rusty_units::{create_kind, create_unit};
+//! # create_unit!(Length, meter => 1.0);
+//! create_kind!(Length, length: 1);
+//! ```
+//!
+//! ## `create_relationships`
+//!
+//! This macro declares relationships between unit structures with `create_unit_operations`, and records the kinds of their operands and results
+//! in a table, which `Quantity` uses to find the kind of a product or quotient once it is passed to `create_kind`.
+//!
+//! ### Example
+//!
+//! ```rust
+//! # use rusty_units::{create_kind, create_relationships, create_unit};
+//! # use rusty_units::units::{Distance, Time};
+//! # create_unit!(Pace, seconds_per_meter => 1.0);
+//! create_kind!(Pace, length: -1, time: 1; operations: PACE_OPERATIONS);
+//! create_relationships! {
+//!     const PACE_OPERATIONS;
+//!     single Pace * Distance => Time,
+//! }
+//! ```
 #[macro_export]
+/// This macro generates `as_*` and `from_*` conversion methods for a given struct.
+///
+/// # Parameters
+/// - `$struct_name`: The name of the struct for which the converters will be implemented.
+/// - `$( $unit_name => $numerator / $denominator ),+`: A list of unit names and their definitions, the number of base units in one of that unit.
+///   The denominator is optional, and each side must be a single token, so compound expressions need parentheses, ie `(2.0 * PI)`.
+///
+/// Writing a definition as a ratio of exactly representable numbers, rather than as a rounded decimal or reciprocal,
+/// means that units which are exact multiples of each other convert exactly, ie 12 inches is exactly 1 foot.
+///
+/// # Example
+/// ```rust
+/// # use rusty_units::create_converters;
+/// struct Length(f64);
+///
+/// create_converters!(Length, meters => 1.0, feet => 3048.0 / 10000.0, inches => 254.0 / 10000.0);
+///
+/// assert_eq!(Length::from_feet(1.0).as_inches(), 12.0);
+/// ```
 macro_rules! create_converters {
-    ($struct_name:ident, $( $unit_name:ident => $conversion_factor:expr ),+ ) => {
-        paste::paste!{
+    ($struct_name:ident, $( $unit_name:ident => $numerator:tt $(/ $denominator:tt)? ),+ $(,)? ) => {
+        $crate::paste::paste!{
         impl $struct_name {
             $(
                 pub fn [< as_ $unit_name >](&self) -> f64 {
-                    self.0 * $conversion_factor
+                    self.0 $(* $denominator)? / $numerator
                 }
 
                 pub fn [< from_ $unit_name >](value: f64) -> Self {
-                    $struct_name(value / $conversion_factor)
+                    $struct_name(value * $numerator $(/ $denominator)?)
                 }
             )+
         }
@@ -93,9 +178,12 @@ macro_rules! create_converters {
 /// - `std::ops::Mul<f64>`: Multiplies an instance of the struct by a scalar of type `f64`.
 /// - `std::ops::Div<f64>`: Divides an instance of the struct by a scalar of type `f64`.
 /// - `std::cmp::PartialEq`: Compares two instances of the struct for equality.
+/// - `hypot`: Returns the hypotenuse of a right triangle with two instances of the struct as its legs.
 /// 
 /// # Example
 /// ```rust
+/// # use rusty_units::create_operations;
+/// #[derive(Debug, Clone, Copy)]
 /// struct MyStruct(f64);
 /// 
 /// create_operations!(MyStruct);
@@ -151,10 +239,9 @@ macro_rules! create_operations {
 }
 
 #[macro_export]
-
 macro_rules! create_dimension {
     ($struct_name:ident) => {
-        impl crate::dimension::Dimension for $struct_name {
+        impl $crate::dimension::Dimension for $struct_name {
             fn as_base_units(&self) -> f64 {
                 self.0
             }
@@ -167,7 +254,10 @@ macro_rules! create_dimension {
 }
 
 #[macro_export]
-/// This macro generates multiplication and division operations between two different unit structures, resulting in a third unit structure.
+/// This macro generates a single multiplication or division operation between two unit structures, resulting in a third unit structure.
+/// 
+/// Most relationships should be declared with `create_unit_operations`, which generates every rearrangement of the relationship, this macro
+/// is for operations that should only be defined one way, like `Radioactivity * Time => f64`, where the reciprocal of a time is already a `Frequency`.
 /// 
 /// # Parameters
 /// - `$lhs_struct`: The left-hand side unit structure for the operation.
@@ -176,27 +266,53 @@ macro_rules! create_dimension {
 /// 
 /// # Generated Implementations
 ///     
-/// - `std::ops::Mul<$rhs_struct>`: Multiplies an instance of the left-hand side struct by an instance of the right-hand side struct, resulting in an instance of the result struct.
-/// - `std::ops::Div<$rhs_struct>`: Divides an instance of the left-hand side struct by an instance of the right-hand side struct, resulting in an instance of the result struct.
-/// 
+/// - `std::ops::Mul<$rhs_struct>` or `std::ops::Div<$rhs_struct>` for the left-hand side struct, resulting in an instance of the result struct.
+///
+/// Either the left-hand side or the result may be `f64` instead of a unit structure, for reciprocals like `f64 / Time => Frequency`,
+/// and dimensionless results like `Frequency * Time => f64`.
 /// 
 /// # Example
 /// ```rust
+/// # use rusty_units::create_single_unit_operation;
 /// struct Length(f64);
 /// struct Time(f64);
 /// struct Speed(f64);
 /// 
-/// create_unit_operations!(Length / Time => Speed);
-/// 
-/// let length = Length(100.0);
-/// let time = Time(10.0);
-/// let speed = length / time; // Speed(10.0)
+/// create_single_unit_operation!(Length / Time => Speed);
 /// 
+/// let speed = Length(100.0) / Time(10.0); // Speed(10.0)
 /// ```
-/// 
-/// # Note
-/// This macro is intended to be used in conjunction with the `create_unit` macro to define unit operations between different unit structures.
-macro_rules! create_unit_operations {
+macro_rules! create_single_unit_operation {
+    (f64 / $rhs_struct:ident => $result_struct:ident) => {
+        impl std::ops::Div<$rhs_struct> for f64 {
+            type Output = $result_struct;
+
+            fn div(self, rhs: $rhs_struct) -> $result_struct {
+                $result_struct(self / rhs.0)
+            }
+        }
+    };
+
+    ($lhs_struct:ident * $rhs_struct:ident => f64) => {
+        impl std::ops::Mul<$rhs_struct> for $lhs_struct {
+            type Output = f64;
+
+            fn mul(self, rhs: $rhs_struct) -> f64 {
+                self.0 * rhs.0
+            }
+        }
+    };
+
+    ($lhs_struct:ident / $rhs_struct:ident => f64) => {
+        impl std::ops::Div<$rhs_struct> for $lhs_struct {
+            type Output = f64;
+
+            fn div(self, rhs: $rhs_struct) -> f64 {
+                self.0 / rhs.0
+            }
+        }
+    };
+
     ($lhs_struct:ident * $rhs_struct:ident => $result_struct:ident) => {
         impl std::ops::Mul<$rhs_struct> for $lhs_struct {
             type Output = $result_struct;
@@ -218,22 +334,490 @@ macro_rules! create_unit_operations {
     };
 }
 
+#[macro_export]
+/// This macro generates every rearrangement of a relationship between unit structures from a single declaration.
+/// 
+/// # Parameters
+/// - `$lhs_struct / $rhs_struct => $result_struct` or `$lhs_struct * $rhs_struct => $result_struct`: The relationship between the structures.
+/// - `$base_struct ^ 2 => $result_struct` or `$base_struct ^ 3 => $result_struct`: A relationship where the result is the square or cube of the base,
+///   ie `Distance ^ 2 => Area`.
+/// 
+/// # Generated Implementations
+/// 
+/// For `A / B => C`, `A / B => C`, `C * B => A`, `B * C => A`, and `A / C => B`.
+/// 
+/// For `A * B => C`, `A * B => C`, `B * A => C`, `C / A => B`, and `C / B => A`.
+/// 
+/// For `A ^ 2 => C`, `A * A => C` and `C / A => A`, along with `A::squared` and `C::sqrt`, and the `Powi<2>` trait, which `A::powi::<2>` uses.
+/// 
+/// For `A ^ 3 => C`, `A::cubed` and `C::cbrt`, and the `Powi<3>` trait, which `A::powi::<3>` uses. The multiplications making up a cube are declared
+/// separately, ie `Area * Distance => Volume`, as they involve the square of the base.
+/// 
+/// As with `create_single_unit_operation`, `f64` can stand in for a dimensionless structure, so `f64 / Time => Frequency` also generates
+/// `Frequency * Time => f64`, `Time * Frequency => f64`, and `f64 / Frequency => Time`. A ratio of two structures, like `A / B => f64`,
+/// only generates `A / B => f64`, as its rearrangements would multiply or divide by a plain number.
+/// 
+/// # Example
+/// ```rust
+/// # use rusty_units::create_unit_operations;
+/// struct Length(f64);
+/// struct Time(f64);
+/// struct Speed(f64);
+/// 
+/// create_unit_operations!(Length / Time => Speed);
+/// 
+/// let speed = Length(100.0) / Time(10.0); // Speed(10.0)
+/// let length = speed * Time(2.0); // Length(20.0)
+/// let time = Length(100.0) / Speed(20.0); // Time(5.0)
+/// ```
+/// 
+/// # Note
+/// This macro is intended to be used in conjunction with the `create_unit` macro to define unit operations between different unit structures.
+/// Each operation can only be implemented once, so two relationships can't share a rearrangement, ie `Distance / Time => LinearVelocity`
+/// and `Distance / LinearVelocity => Time` are the same relationship and should only be declared once. Likewise a structure can only be the
+/// square or cube of one other structure.
+macro_rules! create_unit_operations {
+    ($base_struct:ident ^ 2 => $result_struct:ident) => {
+        $crate::create_single_unit_operation!($base_struct * $base_struct => $result_struct);
+        $crate::create_single_unit_operation!($result_struct / $base_struct => $base_struct);
+
+        impl $crate::dimension::Powi<2> for $base_struct {
+            type Output = $result_struct;
+
+            fn powi(&self) -> $result_struct {
+                $result_struct(self.0.powi(2))
+            }
+        }
+
+        impl $base_struct {
+            pub fn squared(&self) -> $result_struct {
+                $result_struct(self.0.powi(2))
+            }
+        }
+
+        impl $result_struct {
+            pub fn sqrt(&self) -> $base_struct {
+                $base_struct(self.0.sqrt())
+            }
+        }
+    };
+
+    ($base_struct:ident ^ 3 => $result_struct:ident) => {
+        impl $crate::dimension::Powi<3> for $base_struct {
+            type Output = $result_struct;
+
+            fn powi(&self) -> $result_struct {
+                $result_struct(self.0.powi(3))
+            }
+        }
+
+        impl $base_struct {
+            pub fn cubed(&self) -> $result_struct {
+                $result_struct(self.0.powi(3))
+            }
+        }
+
+        impl $result_struct {
+            pub fn cbrt(&self) -> $base_struct {
+                $base_struct(self.0.cbrt())
+            }
+        }
+    };
+
+    ($lhs_struct:ident / $rhs_struct:ident => f64) => {
+        $crate::create_single_unit_operation!($lhs_struct / $rhs_struct => f64);
+    };
+
+    ($lhs_struct:ident * $rhs_struct:ident => $result_struct:ident) => {
+        $crate::create_single_unit_operation!($lhs_struct * $rhs_struct => $result_struct);
+        $crate::create_single_unit_operation!($rhs_struct * $lhs_struct => $result_struct);
+        $crate::create_single_unit_operation!($result_struct / $lhs_struct => $rhs_struct);
+        $crate::create_single_unit_operation!($result_struct / $rhs_struct => $lhs_struct);
+    };
+
+    ($lhs_struct:ident / $rhs_struct:ident => $result_struct:ident) => {
+        $crate::create_single_unit_operation!($lhs_struct / $rhs_struct => $result_struct);
+        $crate::create_single_unit_operation!($result_struct * $rhs_struct => $lhs_struct);
+        $crate::create_single_unit_operation!($rhs_struct * $result_struct => $lhs_struct);
+        $crate::create_single_unit_operation!($lhs_struct / $result_struct => $rhs_struct);
+    };
+}
+
+#[macro_export]
+/// This macro declares relationships between unit structures with `create_unit_operations`, and records every operation it generates,
+/// along with the kinds of its operands and result, in a table of `KindOperation`s, which `Quantity` uses to find the kind of a product or quotient.
+///
+/// # Parameters
+/// - `$vis const $name;`: The visibility and name of the table, which can be preceded by attributes like doc comments.
+/// - The relationships, written as for `create_unit_operations`, separated by commas, and prefixed with `single` for an operation that should
+///   be implemented on its own with `create_single_unit_operation`.
+///
+/// # Generated Implementations
+/// - The operations of each relationship, as generated by `create_unit_operations` or `create_single_unit_operation`.
+/// - A `&[KindOperation]` constant with every multiplication and division generated, except those of cubes.
+///
+/// The operations between the built-in dimensions are always known to `Quantity`. Operations declared outside this crate are only used once
+/// the table is passed to `create_kind` for a kind involved in them. As the operations are implemented too, each one needs a structure from the
+/// crate it is declared in, which outside this crate usually means declaring operations with `single`.
+///
+/// # Example
+/// ```rust
+/// # use rusty_units::{create_kind, create_relationships, create_unit};
+/// use rusty_units::quantity::Quantity;
+/// use rusty_units::units::{Distance, Time};
+///
+/// create_unit!(Pace, seconds_per_meter => 1.0, minutes_per_kilometer => 60.0 / 1000.0);
+/// create_kind!(Pace, length: -1, time: 1; operations: PACE_OPERATIONS);
+///
+/// create_relationships! {
+///     const PACE_OPERATIONS;
+///     single Pace * Distance => Time,
+///     single Distance * Pace => Time,
+/// }
+///
+/// let pace = Quantity::from(Pace::from_minutes_per_kilometer(4.0));
+/// let time = pace * Quantity::from(Distance::from_kilometers(10.0));
+/// assert_eq!(time.kind(), Some("Time"));
+/// assert!((time.into_kind::<Time>().unwrap().as_minutes() - 40.0).abs() < 1e-9);
+/// ```
+macro_rules! create_relationships {
+    (@kind f64) => {
+        None
+    };
+
+    (@kind $kind:ident) => {
+        Some($crate::quantity::KindId::of::<$kind>())
+    };
+
+    (@munch {$($head:tt)*} [$( ($lhs:ident $operator:ident $rhs:ident => $result:ident) )*]) => {
+        $($head)*: &[$crate::quantity::KindOperation] = &[
+            $(
+                $crate::quantity::KindOperation::new(
+                    $crate::create_relationships!(@kind $lhs),
+                    $crate::quantity::Operator::$operator,
+                    $crate::create_relationships!(@kind $rhs),
+                    $crate::create_relationships!(@kind $result),
+                ),
+            )*
+        ];
+    };
+
+    (@munch $head:tt [$($entries:tt)*] single $lhs:ident * $rhs:ident => $result:ident $(, $($rest:tt)*)?) => {
+        $crate::create_single_unit_operation!($lhs * $rhs => $result);
+        $crate::create_relationships!(@munch $head [$($entries)* ($lhs Mul $rhs => $result)] $($($rest)*)?);
+    };
+
+    (@munch $head:tt [$($entries:tt)*] single $lhs:ident / $rhs:ident => $result:ident $(, $($rest:tt)*)?) => {
+        $crate::create_single_unit_operation!($lhs / $rhs => $result);
+        $crate::create_relationships!(@munch $head [$($entries)* ($lhs Div $rhs => $result)] $($($rest)*)?);
+    };
+
+    (@munch $head:tt [$($entries:tt)*] $base:ident ^ 2 => $result:ident $(, $($rest:tt)*)?) => {
+        $crate::create_unit_operations!($base ^ 2 => $result);
+        $crate::create_relationships!(@munch $head [$($entries)* ($base Mul $base => $result) ($result Div $base => $base)] $($($rest)*)?);
+    };
+
+    (@munch $head:tt [$($entries:tt)*] $base:ident ^ 3 => $result:ident $(, $($rest:tt)*)?) => {
+        $crate::create_unit_operations!($base ^ 3 => $result);
+        $crate::create_relationships!(@munch $head [$($entries)*] $($($rest)*)?);
+    };
+
+    (@munch $head:tt [$($entries:tt)*] $lhs:ident / $rhs:ident => f64 $(, $($rest:tt)*)?) => {
+        $crate::create_unit_operations!($lhs / $rhs => f64);
+        $crate::create_relationships!(@munch $head [$($entries)* ($lhs Div $rhs => f64)] $($($rest)*)?);
+    };
+
+    (@munch $head:tt [$($entries:tt)*] $lhs:ident * $rhs:ident => $result:ident $(, $($rest:tt)*)?) => {
+        $crate::create_unit_operations!($lhs * $rhs => $result);
+        $crate::create_relationships!(@munch $head [
+            $($entries)*
+            ($lhs Mul $rhs => $result) ($rhs Mul $lhs => $result) ($result Div $lhs => $rhs) ($result Div $rhs => $lhs)
+        ] $($($rest)*)?);
+    };
+
+    (@munch $head:tt [$($entries:tt)*] $lhs:ident / $rhs:ident => $result:ident $(, $($rest:tt)*)?) => {
+        $crate::create_unit_operations!($lhs / $rhs => $result);
+        $crate::create_relationships!(@munch $head [
+            $($entries)*
+            ($lhs Div $rhs => $result) ($result Mul $rhs => $lhs) ($rhs Mul $result => $lhs) ($lhs Div $result => $rhs)
+        ] $($($rest)*)?);
+    };
+
+    ($(#[$attribute:meta])* $vis:vis const $name:ident; $($relationships:tt)*) => {
+        $crate::create_relationships!(@munch {$(#[$attribute])* $vis const $name} [] $($relationships)*);
+    };
+}
+
+#[macro_export]
+/// This macro generates conversion methods for the result of a unit operation, deriving each unit from a pair of units of the operands,
+/// so that the conversion factors of the result don't need to be maintained by hand.
+///
+/// # Parameters
+/// - `$lhs_struct * $rhs_struct => $result_struct` or `$lhs_struct / $rhs_struct => $result_struct`: The operation the result comes from.
+///   The result struct is created with `create_unit!`, so that it has an exact companion when the `rational` feature is enabled.
+/// - `$( $lhs_unit * $rhs_unit => $result_unit ),+` or `$( $lhs_unit / $rhs_unit => $result_unit ),+`: The units of the operands, as they
+///   appear in their `from_*` methods, and the name of the unit they derive, so `feet / seconds => feet_per_second` generates
+///   `feet_per_second` from `Distance::from_feet` and `Time::from_seconds`.
+///
+/// The name of each derived unit is written out rather than generated, as the units of the operands are plurals, and the name of a derived
+/// unit usually isn't, ie `square_feet` becomes `per_square_foot`.
+///
+/// # Generated Implementations
+/// - `as_*` and `from_*` conversion methods on the result struct for each derived unit, as generated by `create_converters`.
+/// - The same conversions on the exact companion of the result struct, derived from the exact companions of the operands, when the
+///   `rational` feature is enabled.
+///
+/// # Example
+/// ```rust
+/// # use rusty_units::{create_derived_converters, create_unit, create_unit_operations};
+/// create_unit!(Length, meters => 1.0, feet => 3048.0 / 10000.0, miles => 1609344.0 / 1000.0);
+/// create_unit!(Duration, seconds => 1.0, hours => 3600.0);
+/// create_unit!(Speed, meters_per_second => 1.0);
+///
+/// create_unit_operations!(Length / Duration => Speed);
+/// create_derived_converters!(
+///     Length / Duration => Speed,
+///     feet / seconds => feet_per_second,
+///     miles / hours => miles_per_hour
+/// );
+///
+/// let speed = Length::from_miles(60.0) / Duration::from_hours(1.0);
+/// assert!((speed.as_feet_per_second() - 88.0).abs() < 1e-9);
+/// assert!((speed.as_miles_per_hour() - 60.0).abs() < 1e-9);
+/// ```
+macro_rules! create_derived_converters {
+    ($lhs_struct:ident * $rhs_struct:ident => $result_struct:ident, $( $lhs_unit:ident * $rhs_unit:ident => $result_unit:ident ),+ $(,)?) => {
+        $crate::paste::paste!{
+            $crate::create_converters!(
+                $result_struct,
+                $( $result_unit => ($lhs_struct::[< from_ $lhs_unit >](1.0).0 * $rhs_struct::[< from_ $rhs_unit >](1.0).0) ),+
+            );
+        }
+        $crate::create_exact_derived_converters!($lhs_struct * $rhs_struct => $result_struct, $( $lhs_unit * $rhs_unit => $result_unit ),+);
+    };
+
+    ($lhs_struct:ident / $rhs_struct:ident => $result_struct:ident, $( $lhs_unit:ident / $rhs_unit:ident => $result_unit:ident ),+ $(,)?) => {
+        $crate::paste::paste!{
+            $crate::create_converters!(
+                $result_struct,
+                $( $result_unit => ($lhs_struct::[< from_ $lhs_unit >](1.0).0 / $rhs_struct::[< from_ $rhs_unit >](1.0).0) ),+
+            );
+        }
+        $crate::create_exact_derived_converters!($lhs_struct / $rhs_struct => $result_struct, $( $lhs_unit / $rhs_unit => $result_unit ),+);
+    };
+}
+
+#[cfg(feature = "rational")]
+#[macro_export]
+#[doc(hidden)]
+/// This macro generates the derived units of `create_derived_converters` on the exact companion of the result struct.
+macro_rules! create_exact_derived_converters {
+    ($lhs_struct:ident $operator:tt $rhs_struct:ident => $result_struct:ident, $( $lhs_unit:ident $unit_operator:tt $rhs_unit:ident => $result_unit:ident ),+) => {
+        $crate::paste::paste!{
+        impl [< Exact $result_struct >] {
+            $(
+                pub fn [< as_ $result_unit >](&self) -> $crate::rational::BigRational {
+                    &self.0 / Self::[< $result_unit _factor >]()
+                }
+
+                pub fn [< from_ $result_unit >](value: $crate::rational::BigRational) -> Self {
+                    Self(value * Self::[< $result_unit _factor >]())
+                }
+
+                fn [< $result_unit _factor >]() -> &'static $crate::rational::BigRational {
+                    static FACTOR: std::sync::OnceLock<$crate::rational::BigRational> = std::sync::OnceLock::new();
+                    FACTOR.get_or_init(|| {
+                        let one = || $crate::rational::BigRational::from_integer(1.into());
+                        [< Exact $lhs_struct >]::[< from_ $lhs_unit >](one()).0 $operator [< Exact $rhs_struct >]::[< from_ $rhs_unit >](one()).0
+                    })
+                }
+            )+
+        }
+        }
+    };
+}
+
+#[cfg(not(feature = "rational"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! create_exact_derived_converters {
+    ($($tokens:tt)*) => {};
+}
+
+#[macro_export]
+/// This macro implements the `Kind` trait for a given unit structure, tagging it with its SI dimension.
+///
+/// # Parameters
+/// - `$struct_name`: The name of the struct to tag.
+/// - `$( $base: $exponent ),*`: The exponent of each SI base dimension of the struct, out of `length`, `mass`, `time`, `current`,
+///   `temperature`, `amount`, and `luminous_intensity`. Any that are left out are 0.
+/// - `operations: $operations`: Optionally, after a semicolon, the table of operations declared with `create_relationships` that `Quantity`
+///   should use for the struct, besides the operations between the built-in kinds.
+///
+/// # Example
+/// ```rust
+/// # use rusty_units::{create_kind, create_unit};
+/// use rusty_units::dimension::Kind;
+///
+/// create_unit!(Speed, meters_per_second => 1.0);
+/// create_kind!(Speed, length: 1, time: -1);
+///
+/// assert_eq!(Speed::NAME, "Speed");
+/// assert_eq!(Speed::SI_DIMENSION.to_string(), "m·s^-1");
+/// ```
+macro_rules! create_kind {
+    ($struct_name:ident $(, $base:ident : $exponent:literal)* $(; operations: $operations:path)? $(,)?) => {
+        impl $crate::dimension::Kind for $struct_name {
+            const NAME: &'static str = stringify!($struct_name);
+            const SI_DIMENSION: $crate::dimension::SiDimension = $crate::dimension::SiDimension {
+                $( $base: $exponent, )*
+                ..$crate::dimension::SiDimension::DIMENSIONLESS
+            };
+
+            $(
+                fn operations() -> &'static [$crate::quantity::KindOperation] {
+                    $operations
+                }
+            )?
+        }
+    };
+}
+
+#[cfg(feature = "rational")]
+#[macro_export]
+/// This macro generates an exact companion struct for a unit structure, named after it with an `Exact` prefix, which stores its
+/// base magnitude as a `BigRational`. It is only generated when the `rational` feature is enabled, and is called by `create_unit`.
+///
+/// # Parameters
+/// - `$struct_name`: The name of the `f64` unit struct the exact struct is a companion of.
+/// - `$( $unit_name => $numerator / $denominator ),+`: The unit definitions, as accepted by `create_converters`.
+///
+/// # Generated Implementations
+/// - Exact `as_*` and `from_*` conversion methods for each unit in the list.
+/// - `std::ops::Add` and `std::ops::Sub` between exact structs, and `std::ops::Mul` and `std::ops::Div` by a `BigRational` scalar.
+/// - `From` conversion to the `f64` unit struct, and `TryFrom` conversion from it, which fails for NaN and infinite values.
+///
+/// # Example
+/// ```rust
+/// # use rusty_units::create_unit;
+/// use rusty_units::rational::BigRational;
+///
+/// create_unit!(Length, meter => 1.0, foot => 0.3048);
+///
+/// let length = ExactLength::from_foot(BigRational::from_integer(10.into()));
+/// assert_eq!(length.as_meter(), BigRational::new(3048.into(), 1000.into()));
+/// ```
+macro_rules! create_exact_unit {
+    ($struct_name:ident, $( $unit_name:ident => $numerator:tt $(/ $denominator:tt)? ),+ $(,)? ) => {
+        $crate::paste::paste!{
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct [< Exact $struct_name >](pub $crate::rational::BigRational);
+
+        impl [< Exact $struct_name >] {
+            $(
+                pub fn [< as_ $unit_name >](&self) -> $crate::rational::BigRational {
+                    &self.0 / Self::[< $unit_name _factor >]()
+                }
+
+                pub fn [< from_ $unit_name >](value: $crate::rational::BigRational) -> Self {
+                    Self(value * Self::[< $unit_name _factor >]())
+                }
+
+                fn [< $unit_name _factor >]() -> &'static $crate::rational::BigRational {
+                    static FACTOR: std::sync::OnceLock<$crate::rational::BigRational> = std::sync::OnceLock::new();
+                    FACTOR.get_or_init(|| {
+                        // `create_unit!` rejects non-finite factors at compile time, so both sides are finite.
+                        $crate::rational::factor(stringify!($numerator), $numerator).expect("checked by create_unit!")
+                            $(/ $crate::rational::factor(stringify!($denominator), $denominator).expect("checked by create_unit!"))?
+                    })
+                }
+            )+
+        }
+
+        impl std::ops::Add for [< Exact $struct_name >] {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self(self.0 + other.0)
+            }
+        }
+
+        impl std::ops::Sub for [< Exact $struct_name >] {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self(self.0 - other.0)
+            }
+        }
+
+        impl std::ops::Mul<$crate::rational::BigRational> for [< Exact $struct_name >] {
+            type Output = Self;
+
+            fn mul(self, scalar: $crate::rational::BigRational) -> Self {
+                Self(self.0 * scalar)
+            }
+        }
+
+        impl std::ops::Div<$crate::rational::BigRational> for [< Exact $struct_name >] {
+            type Output = Self;
+
+            fn div(self, scalar: $crate::rational::BigRational) -> Self {
+                Self(self.0 / scalar)
+            }
+        }
+
+        impl TryFrom<$struct_name> for [< Exact $struct_name >] {
+            type Error = $crate::rational::NotFiniteError;
+
+            fn try_from(value: $struct_name) -> Result<Self, Self::Error> {
+                $crate::rational::from_f64(value.0).map(Self)
+            }
+        }
+
+        impl From<[< Exact $struct_name >]> for $struct_name {
+            fn from(value: [< Exact $struct_name >]) -> Self {
+                $struct_name($crate::rational::to_f64(&value.0))
+            }
+        }
+        }
+    };
+}
+
+#[cfg(not(feature = "rational"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! create_exact_unit {
+    ($($tokens:tt)*) => {};
+}
+
 #[macro_export]
 /// This macro combines the functionality of `create_converters`, `create_operations`, and `create_dimension` to create a complete unit structure with all necessary methods and traits.
 /// 
 /// # Parameters
 /// - `$struct_name`: The name of the struct representing the unit.
-/// - `$( $unit_name:ident => $conversion_factor:expr ),+`: A list of unit names and their conversion factors to the base unit.
+/// - `$( $unit_name => $numerator / $denominator ),+`: A list of unit names and their definitions in base units, as accepted by `create_converters`.
 /// 
 /// # Generated Implementations
 /// 
 /// - Conversion methods for each unit in the list.
 /// - Arithmetic operations (`Add`, `Sub`, `Mul`, `Div`) for the unit.
 /// - `Dimension` trait implementation for the unit.
+/// - An exact companion struct, when the `rational` feature is enabled.
+/// 
+/// Every conversion factor is checked when the unit is compiled, so a definition that isn't finite and non-zero, like `per_nothing => 1.0 / 0.0`,
+/// fails the build rather than the first conversion.
+/// 
+/// ```rust,compile_fail
+/// # use rusty_units::create_unit;
+/// create_unit!(Length, meter => 1.0, per_nothing => 1.0 / 0.0);
+/// ```
 /// 
 /// # Example
 /// ```rust
+/// # use rusty_units::create_unit;
 /// create_unit!(Length, meter => 1.0, kilometer => 1000.0);
+///
+/// assert_eq!(Length::from_kilometer(1.5).as_meter(), 1500.0);
 /// ```
 /// 
 /// This will generate a `Length` struct with conversion methods, arithmetic operations, and the `Dimension` trait implementation.
@@ -247,14 +831,20 @@ macro_rules! create_unit_operations {
 /// - `create_operations`
 /// - `create_dimension`
 macro_rules! create_unit {
-    ($struct_name:ident, $( $unit_name:ident => $conversion_factor:expr ),+ ) => {
-        paste::paste!{
+    ($struct_name:ident, $( $unit_name:ident => $numerator:tt $(/ $denominator:tt)? ),+ $(,)? ) => {
         #[derive(Debug, Clone, Copy)]
         pub struct $struct_name(pub f64);
 
-        crate::create_converters!($struct_name, $( $unit_name => $conversion_factor ),+);
-        crate::create_operations!($struct_name);
-        crate::create_dimension!($struct_name);
-        }
+        $crate::create_converters!($struct_name, $( $unit_name => $numerator $(/ $denominator)? ),+);
+        $(
+            #[allow(unused_parens)]
+            const _: () = {
+                let factor: f64 = $numerator $(/ $denominator)?;
+                assert!(factor.is_finite() && factor != 0.0, concat!("the factor of `", stringify!($unit_name), "` must be finite and non-zero"));
+            };
+        )+
+        $crate::create_operations!($struct_name);
+        $crate::create_dimension!($struct_name);
+        $crate::create_exact_unit!($struct_name, $( $unit_name => $numerator $(/ $denominator)? ),+);
     };
 }